@@ -3,7 +3,7 @@ mod typescript;
 mod utils;
 
 use neon::{prelude::*, result::Throw};
-use open_api::generate_openapi;
+use open_api::{generate_json_schemas, generate_openapi, set_diagnostics};
 
 pub use open_api::generate_openapi_debug;
 
@@ -11,7 +11,10 @@ fn generate_schemas(mut cx: FunctionContext) -> Result<Handle<JsObject>, Throw>
     let schemas_result: Handle<JsObject> = cx.empty_object();
     let options_handle: Handle<JsObject> = cx.argument(0)?;
 
-    generate_openapi(schemas_result, options_handle, &mut cx)?;
+    let mut diagnostics = vec![];
+    generate_openapi(schemas_result, options_handle, &mut diagnostics, &mut cx)?;
+    generate_json_schemas(schemas_result, options_handle, &mut diagnostics, &mut cx)?;
+    set_diagnostics(schemas_result, &diagnostics, &mut cx)?;
 
     Ok(schemas_result)
 }
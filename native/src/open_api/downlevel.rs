@@ -0,0 +1,174 @@
+use serde_json::{json, Map, Value};
+
+pub fn downlevel_schema(schema: &mut Value) -> () {
+    if let Some(root) = schema.as_object_mut() {
+        root.insert("openapi".into(), json!("3.0.3"));
+    }
+
+    downlevel(schema);
+}
+
+fn downlevel(value: &mut Value) -> () {
+    match value {
+        Value::Array(items) => {
+            for item in items {
+                downlevel(item);
+            }
+        }
+        Value::Object(object) => {
+            downlevel_object(object);
+
+            for (key, child) in object.iter_mut() {
+                match key.as_str() {
+                    "properties" | "patternProperties" | "schemas" => {
+                        if let Some(children) = child.as_object_mut() {
+                            for (_, child) in children.iter_mut() {
+                                downlevel(child);
+                            }
+                        }
+                    }
+                    // payloads are instance data, not schemas, and must be left as written
+                    "example" | "examples" | "default" | "enum" | "const" => {}
+                    _ => downlevel(child),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn downlevel_object(object: &mut Map<String, Value>) -> () {
+    object.remove("$id");
+
+    if let Some(Value::Array(types)) = object.get("type") {
+        let is_nullable = types.iter().any(|data_type| data_type.as_str() == Some("null"));
+        let types: Vec<Value> = types
+            .iter()
+            .filter(|data_type| data_type.as_str() != Some("null"))
+            .cloned()
+            .collect();
+        match types.len() {
+            0 => {
+                object.remove("type");
+            }
+            1 => {
+                object.insert("type".into(), types[0].clone());
+            }
+            _ => {
+                object.remove("type");
+                let any_of = types
+                    .into_iter()
+                    .map(|data_type| match is_nullable {
+                        true => json!({ "type": data_type, "nullable": true }),
+                        false => json!({ "type": data_type }),
+                    })
+                    .collect();
+                object.insert("anyOf".into(), Value::Array(any_of));
+            }
+        }
+
+        if is_nullable && object.contains_key("type") {
+            object.insert("nullable".into(), json!(true));
+        }
+    }
+
+    for key in ["anyOf", "oneOf"] {
+        let mut variants = match object.get(key) {
+            Some(Value::Array(variants)) => variants.clone(),
+            _ => continue,
+        };
+
+        let count = variants.len();
+        variants.retain(|variant| !variant.eq(&json!({ "type": "null" })));
+        if variants.len() == count {
+            continue;
+        }
+
+        // 3.0 only honours nullable next to a type, so a lone typed member is inlined and made nullable,
+        // typed members of a wider union are made nullable on their own, and a member without a type,
+        // like a $ref, is paired with a schema that only accepts null
+        object.remove(key);
+        match variants.len() {
+            1 => match variants.remove(0) {
+                Value::Object(variant) if variant.contains_key("type") => {
+                    for (name, value) in variant {
+                        object.entry(name).or_insert(value);
+                    }
+                    object.insert("nullable".into(), json!(true));
+                }
+                variant => {
+                    let key = match object.contains_key("oneOf") {
+                        true => key,
+                        false => "oneOf",
+                    };
+                    object.insert(key.into(), json!([variant, get_null_schema()]));
+                }
+            },
+            _ => {
+                let has_untyped = variants.iter().any(|variant| variant.get("type").is_none());
+                let mut variants: Vec<Value> = variants.into_iter().map(make_nullable).collect();
+                if has_untyped {
+                    variants.push(get_null_schema());
+                }
+                object.insert(key.into(), Value::Array(variants));
+            }
+        }
+    }
+
+    if let Some(Value::Array(prefix_items)) = object.remove("prefixItems") {
+        // 3.0 has no boolean schemas, so a tuple closed with `items: false` is bounded by maxItems instead
+        if object.get("items") == Some(&Value::Bool(false)) {
+            object.remove("items");
+            object.insert("maxItems".into(), json!(prefix_items.len()));
+        }
+
+        let mut items: Vec<Value> = vec![];
+        for item in prefix_items {
+            if !items.contains(&item) {
+                items.push(item);
+            }
+        }
+
+        if !object.contains_key("items") {
+            match items.len() {
+                0 => {}
+                1 => {
+                    object.insert("items".into(), items.remove(0));
+                }
+                _ => {
+                    object.insert("items".into(), json!({ "anyOf": items }));
+                }
+            }
+        }
+    }
+
+    for (exclusive, inclusive) in [("exclusiveMinimum", "minimum"), ("exclusiveMaximum", "maximum")] {
+        if let Some(Value::Number(limit)) = object.get(exclusive) {
+            let limit = Value::Number(limit.clone());
+            object.insert(inclusive.into(), limit);
+            object.insert(exclusive.into(), json!(true));
+        }
+    }
+
+    if let Some(Value::Array(examples)) = object.get("examples") {
+        if let Some(example) = examples.first().cloned() {
+            object.insert("example".into(), example);
+        }
+        object.remove("examples");
+    }
+}
+
+fn make_nullable(schema: Value) -> Value {
+    match schema {
+        Value::Object(mut schema) if schema.contains_key("type") => {
+            schema.insert("nullable".into(), json!(true));
+            Value::Object(schema)
+        }
+        schema => schema,
+    }
+}
+
+// nullable needs a type beside it, and the enum keeps any value of that type other than null out
+fn get_null_schema() -> Value {
+    json!({ "type": "object", "nullable": true, "enum": [null] })
+}
@@ -1,16 +1,21 @@
-use std::{cell::RefCell, ops::Deref, rc::Rc};
+use std::{cell::RefCell, collections::BTreeMap, ops::Deref, rc::Rc};
 
+use swc_common::{Span, DUMMY_SP};
 use swc_ecma_ast::*;
 
-use crate::typescript::{ModuleCache, NodeKind, SchemyNode};
+use crate::typescript::{NodeKind, SchemyNode};
 
 use super::{
-    schema::{ApiPathOperation, ApiSchema, OpenApi, PathOptions, ResponseOptions},
-    state::{Declaration, Store},
+    schema::{
+        ApiParam, ApiPathOperation, ApiSchema, OpenApi, ParameterForm, PathOptions, ResponseOptions,
+        UnresolvedReferences,
+    },
+    state::{get_commonjs_export_names, Declaration, SchemaReference, Severity, Store, TypeArgument},
 };
 
-pub fn append_schema(open_api: &mut OpenApi, file_path: &str, module_cache: &mut ModuleCache, store: &mut Store) -> () {
-    let root = module_cache.parse(&file_path);
+pub fn append_schema(open_api: &mut OpenApi, file_path: &str, store: &mut Store) -> () {
+    let root = store.parse_module(&file_path);
+
     find_paths(open_api, root.clone(), file_path, store);
 
     while store.has_unrecognized_local_types(file_path) {
@@ -18,9 +23,10 @@ pub fn append_schema(open_api: &mut OpenApi, file_path: &str, module_cache: &mut
     }
 }
 
-pub fn append_deferred_schemas(open_api: &mut OpenApi, module_cache: &mut ModuleCache, store: &mut Store) -> () {
+pub fn append_deferred_schemas(open_api: &mut OpenApi, store: &mut Store) -> () {
     while let Some(file_path) = store.next_module() {
-        let deferred_root = module_cache.parse(&file_path);
+        let deferred_root = store.parse_module(&file_path);
+
         for item in deferred_root.children() {
             define_external_schema(open_api, item, &file_path, store);
         }
@@ -31,6 +37,93 @@ pub fn append_deferred_schemas(open_api: &mut OpenApi, module_cache: &mut Module
     }
 }
 
+pub fn append_type_schemas(
+    open_api: &mut OpenApi,
+    file_path: &str,
+    type_names: &Vec<String>,
+    store: &mut Store,
+) -> Vec<String> {
+    let root = store.parse_module(&file_path);
+
+    let mut exported_names: Vec<String> = root
+        .children()
+        .iter()
+        .filter_map(|item| get_schema_marker(item))
+        .collect();
+    for type_name in type_names {
+        let is_local = matches!(
+            store.get_root_declaration(file_path, type_name),
+            Some(Declaration::Type { .. }) | Some(Declaration::Value { .. })
+        );
+        if !exported_names.contains(type_name) && is_local {
+            exported_names.push(type_name.clone());
+        }
+    }
+
+    let mut schema_names = vec![];
+    for type_name in exported_names {
+        let schema_name = store.get_schema_name(file_path, &type_name);
+        define_local_schema(&type_name, &schema_name, open_api, file_path, store);
+        schema_names.push(schema_name);
+    }
+
+    while store.has_unrecognized_local_types(file_path) {
+        define_local_schemas(file_path, open_api, store);
+    }
+
+    schema_names
+}
+
+fn get_schema_marker(root: &Rc<SchemyNode<'static>>) -> Option<String> {
+    let alias = match root.kind {
+        NodeKind::ModuleItem(ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+            decl: Decl::TsTypeAlias(alias),
+            ..
+        }))) => alias,
+        NodeKind::ModuleItem(ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias)))) => alias,
+        _ => return None,
+    };
+
+    match &*alias.type_ann {
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(identifier),
+            ..
+        }) if identifier.sym.eq("LilSchema") => Some(alias.id.sym.to_string()),
+        _ => None,
+    }
+}
+
+// unresolved references fall back to an empty schema and are reported as warnings, unless they are errors, in
+// which case their messages are returned for generation to fail with
+pub fn reconcile_references(open_api: &mut OpenApi, store: &mut Store) -> Vec<String> {
+    let unresolved: Vec<SchemaReference> = store
+        .references()
+        .iter()
+        .filter(|reference| !open_api.components.contains_schema(&reference.schema_name))
+        .cloned()
+        .collect();
+
+    let mut errors = vec![];
+    for reference in unresolved {
+        let message = format!("Could not resolve the type '{}'", reference.schema_name);
+        match store.options().unresolved_references {
+            UnresolvedReferences::Empty => {
+                open_api.components.schema(&reference.schema_name);
+                store.add_diagnostic_at(Severity::Warning, &message, &reference.file_path, reference.span);
+            }
+            UnresolvedReferences::Error => {
+                errors.push(format!(
+                    "{} referenced at {}",
+                    message,
+                    store.reference_location(&reference)
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
 fn define_local_schemas(file_path: &str, open_api: &mut OpenApi, store: &mut Store) {
     for local_type in store.recognize_local_types(file_path) {
         define_local_schema(
@@ -49,10 +142,19 @@ fn find_paths(open_api: &mut OpenApi, root: Rc<SchemyNode<'static>>, file_path:
     for child in root.children() {
         match &child.kind {
             NodeKind::Ident(raw_ident) if raw_ident.sym.eq("LilPath") => {
-                let parent = child.parent().unwrap().parent().unwrap();
-                store.add_child_scope(file_path);
-                add_path(open_api, parent, file_path, store);
-                store.parent_scope(file_path);
+                match child.parent().and_then(|parent| parent.parent()) {
+                    Some(parent) => {
+                        store.add_child_scope(file_path);
+                        add_path(open_api, parent, file_path, store);
+                        store.parent_scope(file_path);
+                    }
+                    None => store.add_diagnostic(
+                        Severity::Warning,
+                        "LilPath must be called with a route handler and route options",
+                        file_path,
+                        Some(&child),
+                    ),
+                }
             }
             _ => find_paths(open_api, child, file_path, store),
         }
@@ -61,17 +163,39 @@ fn find_paths(open_api: &mut OpenApi, root: Rc<SchemyNode<'static>>, file_path:
 
 fn add_path(open_api: &mut OpenApi, root: Rc<SchemyNode<'static>>, file_path: &str, store: &mut Store) -> () {
     let args = root.args();
-    let route_handler = args.first().unwrap().as_arrow_expr().unwrap();
-    let route_options = args.last().unwrap();
+    let (route_handler, route_options) = match (args.first().and_then(|arg| arg.as_arrow_expr()), args.get(1)) {
+        (Some(route_handler), Some(route_options)) => (route_handler, route_options.clone()),
+        _ => {
+            store.add_diagnostic(
+                Severity::Error,
+                "LilPath must be called with an arrow function route handler and route options",
+                file_path,
+                Some(&root),
+            );
+            return;
+        }
+    };
     let options = get_path_options(route_options.clone());
 
     if let Some(path) = &options.path {
         if let Some(method) = &options.method {
-            let operation = open_api.path(&path).add_operation(&method).clone();
+            let operation = match open_api.path(&path).add_operation(&method) {
+                Some(operation) => operation.clone(),
+                None => {
+                    store.add_diagnostic(
+                        Severity::Error,
+                        &format!("Unsupported http method '{}' for path '{}'", method, path),
+                        file_path,
+                        Some(&route_options),
+                    );
+                    return;
+                }
+            };
 
             {
                 let mut borrow = (*operation).borrow_mut();
                 borrow.tags(options.tags.clone());
+                borrow.operation_id(options.operation_id.clone());
             }
 
             add_request_details(&operation, route_handler.clone(), file_path, &options, store);
@@ -92,7 +216,42 @@ fn add_request_details(
 
     store.add_child_scope(file_path);
 
-    find_response(operation, route_handler, file_path, path_options, &mut "".into(), store);
+    find_response(
+        operation,
+        route_handler.clone(),
+        file_path,
+        path_options,
+        &mut "".into(),
+        store,
+    );
+
+    let has_responses = (**operation).borrow().has_responses();
+    if !has_responses && store.options().infer_responses {
+        let params: Vec<Option<String>> = route_handler.params().iter().map(get_binding_name).collect();
+        let reply = params.get(1).cloned().flatten();
+
+        // a handler that calls methods on its second parameter takes a request and a reply, so the first
+        // parameter is the request rather than a context and assignments to it are not responses
+        let context = match &reply {
+            Some(reply) if has_reply_calls(route_handler.clone(), reply) => None,
+            _ => params.get(0).cloned().flatten(),
+        };
+
+        infer_responses(
+            operation,
+            route_handler.clone(),
+            context.as_deref(),
+            reply.as_deref(),
+            file_path,
+            path_options,
+            store,
+        );
+    }
+
+    let has_responses = (**operation).borrow().has_responses();
+    if !has_responses && store.options().return_type_responses {
+        add_return_type_response(operation, route_handler, file_path, path_options, store);
+    }
 
     store.parent_scope(file_path);
 }
@@ -141,6 +300,27 @@ fn add_request_params(
                 store,
             );
         }
+        NodeKind::Ident(identifier) if identifier.sym.eq("LilHeaders") => {
+            add_param_group(
+                operation,
+                "header",
+                find_parent_type_ref(root),
+                file_path,
+                path_options,
+                store,
+            );
+        }
+        NodeKind::Ident(identifier) if identifier.sym.eq("LilQueryParams") => {
+            add_param_group(
+                operation,
+                "query",
+                find_parent_type_ref(root),
+                file_path,
+                path_options,
+                store,
+            );
+        }
+        NodeKind::Ident(identifier) if identifier.sym.eq("LilParamStyle") => {}
         NodeKind::Ident(identifier) => match store.get_root_declaration(file_path, &identifier.sym) {
             Some(Declaration::Import { name, source_file_name }) => {
                 store.defer_operation_type(&source_file_name, operation, &name);
@@ -167,13 +347,29 @@ fn add_param_details(
     path_options: &PathOptions,
     store: &mut Store,
 ) {
+    let parameter_name = match get_parameter_name(root.clone()) {
+        Some(parameter_name) => parameter_name,
+        None => {
+            store.add_diagnostic(
+                Severity::Error,
+                &format!("Could not find the name of a {} parameter", location),
+                file_path,
+                Some(&root),
+            );
+            return;
+        }
+    };
+
     let mut operation = (**operation).borrow_mut();
-    let parameter_name = get_parameter_name(root.clone());
+    let description = get_parameter_description(root.clone(), file_path, store);
     let operation_param = operation.param(&parameter_name, location);
+    let use_content = store.options().parameter_form == ParameterForm::Content;
+
+    operation_param.description(description);
 
     let type_params = root.params();
     if let Some(type_param) = type_params.get(0) {
-        let param_schema = operation_param.content(None).schema();
+        let param_schema = get_param_schema(operation_param, use_content);
         define_schema_details(param_schema, &type_param, file_path, false, path_options, store);
     }
 
@@ -196,10 +392,7 @@ fn add_param_details(
         Some(param) => match param.kind {
             NodeKind::TsLitType(raw_lit) => match &raw_lit.lit {
                 TsLit::Str(raw_str) => {
-                    operation_param
-                        .content(None)
-                        .schema()
-                        .format(Some(raw_str.value.to_string()));
+                    get_param_schema(operation_param, use_content).format(Some(raw_str.value.to_string()));
                 }
                 _ => {}
             },
@@ -207,6 +400,174 @@ fn add_param_details(
         },
         None => {}
     }
+
+    // style, explode and allowReserved are not allowed alongside content
+    match type_params.get(3) {
+        Some(param) if !use_content => add_param_style(operation_param, param.clone()),
+        _ => {}
+    }
+}
+
+fn add_param_group(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    location: &str,
+    root: Rc<SchemyNode<'static>>,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) {
+    let type_params = root.params();
+    if let Some(type_param) = type_params.get(0) {
+        add_param_group_members(operation, location, type_param.clone(), file_path, path_options, store);
+    }
+}
+
+fn add_param_group_members(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    location: &str,
+    root: Rc<SchemyNode<'static>>,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) {
+    match root.kind {
+        NodeKind::TsTypeLit(_) => {
+            for member in root.members() {
+                add_param_group_member(operation, location, member, file_path, path_options, store);
+            }
+        }
+        NodeKind::TsInterfaceDecl(_) => {
+            for extend in root.extends() {
+                add_param_group_members(operation, location, extend, file_path, path_options, store);
+            }
+
+            if let Some(interface_body) = root.interface_body() {
+                for member in interface_body.members() {
+                    add_param_group_member(operation, location, member, file_path, path_options, store);
+                }
+            }
+        }
+        NodeKind::TsTypeRef(raw_ref) => match &raw_ref.type_name {
+            TsEntityName::Ident(identifier) => {
+                add_param_group_from_identifier(operation, location, &identifier.sym, file_path, path_options, store)
+            }
+            _ => {}
+        },
+        NodeKind::TsExprWithTypeArgs(raw_expr) => match &*raw_expr.expr {
+            Expr::Ident(identifier) => {
+                add_param_group_from_identifier(operation, location, &identifier.sym, file_path, path_options, store)
+            }
+            _ => {}
+        },
+        NodeKind::TsTypeAliasDecl(_)
+        | NodeKind::TsType(_)
+        | NodeKind::TsUnionOrIntersectionType(_)
+        | NodeKind::TsIntersectionType(_) => {
+            for child in root.children() {
+                add_param_group_members(operation, location, child, file_path, path_options, store);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn add_param_group_from_identifier(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    location: &str,
+    identifier: &str,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) {
+    match store.get_root_declaration(file_path, identifier) {
+        Some(Declaration::Type { node }) => {
+            add_param_group_members(operation, location, node, file_path, path_options, store);
+        }
+        Some(Declaration::Import { name, source_file_name }) => {
+            store.defer_param_group(&source_file_name, operation, &name, location);
+        }
+        _ => {}
+    }
+}
+
+fn add_param_group_member(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    location: &str,
+    member: Rc<SchemyNode<'static>>,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) {
+    match member.kind {
+        NodeKind::TsTypeElement(TsTypeElement::TsPropertySignature(raw_prop)) => {
+            let name = match &*raw_prop.key {
+                Expr::Ident(identifier) => identifier.sym.to_string(),
+                Expr::Lit(Lit::Str(raw_str)) => raw_str.value.to_string(),
+                _ => return,
+            };
+
+            let description = store.get_description(file_path, raw_prop.span.lo);
+            let use_content = store.options().parameter_form == ParameterForm::Content;
+
+            let mut operation = (**operation).borrow_mut();
+            let operation_param = operation.param(&name, location);
+            operation_param.required(!raw_prop.optional).description(description);
+
+            if let Some(annotation) = member.type_ann() {
+                let param_schema = get_param_schema(operation_param, use_content);
+                define_schema_details(param_schema, &annotation, file_path, false, path_options, store);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn get_param_schema(operation_param: &mut ApiParam, use_content: bool) -> &mut ApiSchema {
+    match use_content {
+        true => operation_param.content(None).schema(),
+        false => operation_param.schema(),
+    }
+}
+
+fn add_param_style(operation_param: &mut ApiParam, root: Rc<SchemyNode<'static>>) {
+    match root.kind {
+        NodeKind::TsTypeRef(raw_ref) => match &raw_ref.type_name {
+            TsEntityName::Ident(identifier) if identifier.sym.eq("LilParamStyle") => {
+                let params = root.params();
+
+                let style = match params.get(0).map(|p| &p.kind) {
+                    Some(NodeKind::TsLitType(raw)) => match &raw.lit {
+                        TsLit::Str(raw_str) => Some(raw_str.value.to_string()),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                let explode = match params.get(1).map(|p| &p.kind) {
+                    Some(NodeKind::TsLitType(raw)) => match &raw.lit {
+                        TsLit::Bool(raw_bool) => Some(raw_bool.value),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                let allow_reserved = match params.get(2).map(|p| &p.kind) {
+                    Some(NodeKind::TsLitType(raw)) => match &raw.lit {
+                        TsLit::Bool(raw_bool) => Some(raw_bool.value),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                operation_param
+                    .style(style)
+                    .explode(explode)
+                    .allow_reserved(allow_reserved);
+            }
+            _ => {}
+        },
+        _ => {}
+    }
 }
 
 fn find_response(
@@ -247,89 +608,446 @@ fn add_response(
         None => None,
     };
 
-    match args.get(0) {
-        Some(response_type) if options.is_some() => {
-            add_response_details(
-                &response_type,
-                &options.unwrap(),
-                file_path,
-                operation,
-                path_options,
-                store,
-            );
+    match (args.get(0), options) {
+        (Some(response_type), Some(options)) if options.status_code.is_some() && options.description.is_some() => {
+            add_response_details(&response_type, &options, file_path, operation, path_options, store);
         }
+        (Some(_), Some(_)) => store.add_diagnostic(
+            Severity::Error,
+            "LilResponse options must include a statusCode and a description",
+            file_path,
+            Some(&root),
+        ),
         _ => {}
     };
 }
 
-fn add_response_details(
-    root: &Rc<SchemyNode<'static>>,
-    options: &ResponseOptions,
+fn infer_responses(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    root: Rc<SchemyNode<'static>>,
+    context: Option<&str>,
+    reply: Option<&str>,
     file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    if let Some(context) = context {
+        infer_context_responses(operation, root.clone(), context, file_path, path_options, store);
+    }
+
+    for child in root.children() {
+        match (&child.kind, reply) {
+            (NodeKind::CallExpr(raw), Some(reply)) => {
+                infer_reply_response(operation, &child, raw, reply, file_path, path_options, store)
+            }
+            _ => {}
+        }
+
+        infer_responses(operation, child, context, reply, file_path, path_options, store);
+    }
+}
+
+fn has_reply_calls(root: Rc<SchemyNode<'static>>, reply: &str) -> bool {
+    root.children().into_iter().any(|child| {
+        let is_reply_call = match child.kind {
+            NodeKind::CallExpr(CallExpr {
+                callee: Callee::Expr(callee),
+                ..
+            }) => match &**callee {
+                Expr::Member(MemberExpr { obj, .. }) => get_chain_receiver(obj).map_or(false, |r| r.eq(reply)),
+                _ => false,
+            },
+            _ => false,
+        };
+
+        is_reply_call || has_reply_calls(child, reply)
+    })
+}
+
+fn infer_reply_response(
     operation: &Rc<RefCell<ApiPathOperation>>,
+    root: &Rc<SchemyNode<'static>>,
+    call: &CallExpr,
+    reply: &str,
+    file_path: &str,
     path_options: &PathOptions,
     store: &mut Store,
-) {
-    let status_code = options.status_code.as_ref().unwrap();
-    let description = options.description.as_ref().unwrap();
-    let media_type = options.media_type.as_ref().map(|s| s.as_str());
+) -> () {
+    let object = match &call.callee {
+        Callee::Expr(callee) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) if prop.sym.eq("send") || prop.sym.eq("json") => obj,
+            _ => return,
+        },
+        _ => return,
+    };
 
-    let mut operation = (**operation).borrow_mut();
-    let response = operation.response(&status_code, &description);
-    let content = response.content(media_type);
+    if get_chain_receiver(object).map_or(true, |receiver| !receiver.eq(reply)) {
+        return;
+    }
+
+    let args = root.args();
+    if args.iter().any(|arg| has_lil_response(arg.clone())) {
+        return;
+    }
 
-    define_schema_details(content.schema(), root, file_path, true, path_options, store);
+    let status_code = match get_chained_status_code(object, root) {
+        Some(status_code) => status_code,
+        None => return,
+    };
 
-    content.example(options.example.clone());
+    add_inferred_response(operation, &status_code, args.first(), file_path, path_options, store);
 }
 
-fn add_body_param_details(
+fn infer_context_responses(
     operation: &Rc<RefCell<ApiPathOperation>>,
     root: Rc<SchemyNode<'static>>,
+    context: &str,
     file_path: &str,
     path_options: &PathOptions,
     store: &mut Store,
 ) -> () {
-    let mut operation = (**operation).borrow_mut();
-    let operation_param = operation.body();
-    let type_params = root.params();
+    let mut bodies = vec![];
+    let mut status_code = None;
+    let mut has_status = false;
 
-    let content: Option<&str> = match type_params.get(2) {
-        Some(param) => match &param.kind {
-            NodeKind::TsLitType(raw) => match &raw.lit {
-                TsLit::Str(raw_str) => Some(&raw_str.value),
+    for child in root.children() {
+        let assign = match child.kind {
+            NodeKind::ExprStmt(raw) => match &*raw.expr {
+                Expr::Assign(assign) if assign.op == AssignOp::Assign => assign,
+                _ => continue,
+            },
+            _ => continue,
+        };
+
+        let target = match &assign.left {
+            PatOrExpr::Expr(expr) => get_member_name(expr, context),
+            PatOrExpr::Pat(pat) => match &**pat {
+                Pat::Expr(expr) => get_member_name(expr, context),
                 _ => None,
             },
-            _ => None,
-        },
-        None => None,
-    };
+        };
 
-    match type_params.get(0) {
-        Some(param) => match &param.kind {
-            NodeKind::TsTypeLit(raw_lit) => {
-                let child = root.to_child(NodeKind::TsTypeLit(raw_lit));
-                define_schema_details(
-                    operation_param.content(content).schema(),
-                    &child,
-                    file_path,
-                    false,
-                    &PathOptions::default(),
-                    store,
-                );
-            }
-            NodeKind::TsKeywordType(raw_keyword) => match raw_keyword.kind {
-                TsKeywordTypeKind::TsNumberKeyword => {
-                    operation_param.content(content).schema().data_type("number");
+        match target {
+            Some(name) if name.eq("body") => {
+                for assignment in child.children() {
+                    bodies.extend(assignment.children());
                 }
-                TsKeywordTypeKind::TsBooleanKeyword => {
-                    operation_param.content(content).schema().data_type("boolean");
-                }
-                TsKeywordTypeKind::TsStringKeyword => {
-                    operation_param.content(content).schema().data_type("string");
-                }
-                _ => {}
-            },
+            }
+            Some(name) if name.eq("status") => {
+                has_status = true;
+                status_code = get_status_code(&assign.right, &child);
+            }
+            _ => {}
+        }
+    }
+
+    let status_code = match (has_status, status_code) {
+        (false, _) => String::from("200"),
+        (true, Some(status_code)) => status_code,
+        (true, None) => return,
+    };
+
+    if bodies.is_empty() {
+        if has_status {
+            add_inferred_response(operation, &status_code, None, file_path, path_options, store);
+        }
+    } else {
+        for body in bodies {
+            add_inferred_response(operation, &status_code, Some(&body), file_path, path_options, store);
+        }
+    }
+}
+
+fn add_inferred_response(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    status_code: &str,
+    body: Option<&Rc<SchemyNode<'static>>>,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let description = get_status_description(status_code);
+
+    match body {
+        Some(body) => {
+            let mut schema = ApiSchema::new();
+            define_schema_details(&mut schema, body, file_path, true, path_options, store);
+
+            let mut operation = (**operation).borrow_mut();
+            operation
+                .response(status_code, &description)
+                .content(None)
+                .add_schema(schema);
+        }
+        None => {
+            let mut operation = (**operation).borrow_mut();
+            operation.response(status_code, &description);
+        }
+    }
+}
+
+fn has_lil_response(root: Rc<SchemyNode<'static>>) -> bool {
+    root.children().into_iter().any(|child| match child.kind {
+        NodeKind::Ident(raw) if raw.sym.eq("LilResponse") => true,
+        _ => has_lil_response(child),
+    })
+}
+
+fn get_member_name(expr: &Expr, object: &str) -> Option<String> {
+    match expr {
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if obj.as_ident().map_or(false, |obj| obj.sym.eq(object)) => Some(prop.sym.to_string()),
+        _ => None,
+    }
+}
+
+fn get_binding_name(root: &Rc<SchemyNode<'static>>) -> Option<String> {
+    match root.kind {
+        NodeKind::Pat(Pat::Ident(raw)) => Some(raw.id.sym.to_string()),
+        _ => None,
+    }
+}
+
+fn get_chain_receiver(expr: &Expr) -> Option<&str> {
+    match expr {
+        Expr::Ident(raw) => Some(&raw.sym),
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            ..
+        }) => match &**callee {
+            Expr::Member(MemberExpr { obj, .. }) => get_chain_receiver(obj),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn get_chained_status_code(expr: &Expr, root: &Rc<SchemyNode<'static>>) -> Option<String> {
+    match expr {
+        Expr::Call(CallExpr {
+            callee: Callee::Expr(callee),
+            args,
+            ..
+        }) => match &**callee {
+            Expr::Member(MemberExpr {
+                obj,
+                prop: MemberProp::Ident(prop),
+                ..
+            }) => {
+                if prop.sym.eq("code") || prop.sym.eq("status") {
+                    args.first().and_then(|arg| get_status_code(&arg.expr, root))
+                } else {
+                    get_chained_status_code(obj, root)
+                }
+            }
+            _ => None,
+        },
+        Expr::Ident(_) => Some(String::from("200")),
+        _ => None,
+    }
+}
+
+fn get_status_code(expr: &Expr, root: &Rc<SchemyNode<'static>>) -> Option<String> {
+    match expr {
+        Expr::Lit(Lit::Num(raw)) => Some(format!("{}", raw.value)),
+        Expr::Ident(raw) => find_status_constant(root.clone(), &raw.sym),
+        Expr::Paren(raw) => get_status_code(&raw.expr, root),
+        Expr::TsAs(raw) => get_status_code(&raw.expr, root),
+        Expr::TsConstAssertion(raw) => get_status_code(&raw.expr, root),
+        _ => None,
+    }
+}
+
+fn find_status_constant(root: Rc<SchemyNode<'static>>, name: &str) -> Option<String> {
+    let status_code = match &root.kind {
+        NodeKind::Module(raw) => raw.body.iter().find_map(|item| match item {
+            ModuleItem::Stmt(stmt) => get_statement_constant(stmt, name),
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(raw)) => match &raw.decl {
+                Decl::Var(var) => get_variable_constant(var, name),
+                _ => None,
+            },
+            _ => None,
+        }),
+        NodeKind::BlockStmt(raw) => raw.stmts.iter().find_map(|stmt| get_statement_constant(stmt, name)),
+        NodeKind::BlockStmtOrExpr(BlockStmtOrExpr::BlockStmt(raw)) => {
+            raw.stmts.iter().find_map(|stmt| get_statement_constant(stmt, name))
+        }
+        _ => None,
+    };
+
+    match status_code {
+        Some(status_code) => Some(status_code),
+        None => root.parent().and_then(|parent| find_status_constant(parent, name)),
+    }
+}
+
+fn get_statement_constant(stmt: &Stmt, name: &str) -> Option<String> {
+    match stmt {
+        Stmt::Decl(Decl::Var(var)) => get_variable_constant(var, name),
+        _ => None,
+    }
+}
+
+fn get_variable_constant(var: &VarDecl, name: &str) -> Option<String> {
+    var.decls.iter().find_map(|decl| match (&decl.name, &decl.init) {
+        (Pat::Ident(ident), Some(init)) if ident.id.sym.eq(name) => match &**init {
+            Expr::Lit(Lit::Num(raw)) => Some(format!("{}", raw.value)),
+            _ => None,
+        },
+        _ => None,
+    })
+}
+
+fn get_status_description(status_code: &str) -> String {
+    match status_code {
+        "200" => "OK",
+        "201" => "Created",
+        "202" => "Accepted",
+        "204" => "No Content",
+        "301" => "Moved Permanently",
+        "302" => "Found",
+        "304" => "Not Modified",
+        "400" => "Bad Request",
+        "401" => "Unauthorized",
+        "403" => "Forbidden",
+        "404" => "Not Found",
+        "409" => "Conflict",
+        "422" => "Unprocessable Entity",
+        "500" => "Internal Server Error",
+        "503" => "Service Unavailable",
+        _ => "Response",
+    }
+    .into()
+}
+
+fn add_return_type_response(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    route_handler: Rc<SchemyNode<'static>>,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let return_type = match route_handler.return_type().and_then(|r| unwrap_return_type(r)) {
+        Some(return_type) => return_type,
+        None => return,
+    };
+
+    let mut schema = ApiSchema::new();
+    define_schema_details(&mut schema, &return_type, file_path, true, path_options, store);
+
+    let description = store.options().return_type_description.clone();
+    let mut operation = (**operation).borrow_mut();
+    operation.response("200", &description).content(None).add_schema(schema);
+}
+
+fn unwrap_return_type(root: Rc<SchemyNode<'static>>) -> Option<Rc<SchemyNode<'static>>> {
+    match root.kind {
+        NodeKind::TsTypeAnnotation(_) => root.children().into_iter().next().and_then(|c| unwrap_return_type(c)),
+        NodeKind::TsType(_) => root.children().into_iter().next().and_then(|c| unwrap_return_type(c)),
+        NodeKind::TsTypeRef(raw) => match &raw.type_name {
+            TsEntityName::Ident(ident) if ident.sym.eq("Promise") || ident.sym.eq("Awaited") => root
+                .type_params()
+                .into_iter()
+                .next()
+                .and_then(|p| unwrap_return_type(p)),
+            _ => Some(root),
+        },
+        NodeKind::TsKeywordType(raw) => match raw.kind {
+            TsKeywordTypeKind::TsVoidKeyword
+            | TsKeywordTypeKind::TsUndefinedKeyword
+            | TsKeywordTypeKind::TsNeverKeyword
+            | TsKeywordTypeKind::TsAnyKeyword
+            | TsKeywordTypeKind::TsUnknownKeyword => None,
+            _ => Some(root),
+        },
+        _ => Some(root),
+    }
+}
+
+fn add_response_details(
+    root: &Rc<SchemyNode<'static>>,
+    options: &ResponseOptions,
+    file_path: &str,
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    path_options: &PathOptions,
+    store: &mut Store,
+) {
+    let status_code = options.status_code.as_ref().unwrap();
+    let description = options.description.as_ref().unwrap();
+
+    let mut schema = ApiSchema::new();
+    define_schema_details(&mut schema, root, file_path, true, path_options, store);
+
+    let mut operation = (**operation).borrow_mut();
+    let response = operation.response(&status_code, &description);
+
+    let media_types = match options.media_types.is_empty() {
+        true => vec![None],
+        false => options.media_types.iter().map(|m| Some(m.as_str())).collect(),
+    };
+
+    for media_type in media_types {
+        response
+            .content(media_type)
+            .add_schema(schema.clone())
+            .example(options.example.clone());
+    }
+}
+
+fn add_body_param_details(
+    operation: &Rc<RefCell<ApiPathOperation>>,
+    root: Rc<SchemyNode<'static>>,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let mut operation = (**operation).borrow_mut();
+    let operation_param = operation.body();
+    let type_params = root.params();
+
+    let content: Option<&str> = match type_params.get(2) {
+        Some(param) => match &param.kind {
+            NodeKind::TsLitType(raw) => match &raw.lit {
+                TsLit::Str(raw_str) => Some(&raw_str.value),
+                _ => None,
+            },
+            _ => None,
+        },
+        None => None,
+    };
+
+    match type_params.get(0) {
+        Some(param) => match &param.kind {
+            NodeKind::TsTypeLit(raw_lit) => {
+                let child = root.to_child(NodeKind::TsTypeLit(raw_lit));
+                define_schema_details(
+                    operation_param.content(content).schema(),
+                    &child,
+                    file_path,
+                    false,
+                    &PathOptions::default(),
+                    store,
+                );
+            }
+            NodeKind::TsKeywordType(raw_keyword) => match raw_keyword.kind {
+                TsKeywordTypeKind::TsNumberKeyword => {
+                    operation_param.content(content).schema().data_type("number");
+                }
+                TsKeywordTypeKind::TsBooleanKeyword => {
+                    operation_param.content(content).schema().data_type("boolean");
+                }
+                TsKeywordTypeKind::TsStringKeyword => {
+                    operation_param.content(content).schema().data_type("string");
+                }
+                _ => {}
+            },
             NodeKind::TsTypeRef(raw_type) => match &raw_type.type_name {
                 TsEntityName::Ident(identifier) => {
                     let root_schema = operation_param.content(content).schema();
@@ -389,7 +1107,7 @@ fn define_local_schema(
             store.defer_external_type(&source_file_name, schema_name.into(), &type_name);
         }
         Some(Declaration::Type { node }) => {
-            let schema = open_api.components.schema_with_id(schema_name);
+            let schema = open_api.components.schema(schema_name);
             define_schema_details(schema, &node, file_path, false, &PathOptions::default(), store);
         }
         _ => {}
@@ -421,6 +1139,13 @@ fn define_external_schema(
                 define_external_schema_maybe(open_api, &raw_alias.id.sym, file_path, store)
             }
             Decl::TsEnum(ref raw_alias) => define_external_schema_maybe(open_api, &raw_alias.id.sym, file_path, store),
+            Decl::Var(ref raw_var) => {
+                for declarator in &raw_var.decls {
+                    if let Pat::Ident(identifier) = &declarator.name {
+                        define_external_schema_maybe(open_api, &identifier.id.sym, file_path, store)
+                    }
+                }
+            }
             _ => {}
         },
         NodeKind::ModuleItem(ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(raw_export))) => {
@@ -445,6 +1170,14 @@ fn define_external_schema(
                 }
             }
         }
+        NodeKind::ModuleItem(ModuleItem::ModuleDecl(ModuleDecl::TsExportAssignment(_))) => {
+            define_external_schema_maybe(open_api, "default", file_path, store)
+        }
+        NodeKind::ModuleItem(ModuleItem::Stmt(Stmt::Expr(raw_stmt))) => {
+            for name in get_commonjs_export_names(&raw_stmt.expr) {
+                define_external_schema_maybe(open_api, &name, file_path, store)
+            }
+        }
         NodeKind::ModuleItem(ModuleItem::Stmt(Stmt::Decl(Decl::TsEnum(raw_enum)))) => {
             define_external_schema_maybe(open_api, &raw_enum.id.sym, file_path, store)
         }
@@ -468,8 +1201,31 @@ fn define_external_schema(
 }
 
 fn define_external_schema_maybe(open_api: &mut OpenApi, type_name: &str, file_path: &str, store: &mut Store) -> () {
-    if open_api.components.contains_schema(type_name) {
-        return;
+    for param_group in store.recognize_param_groups(type_name, file_path) {
+        match store.get_root_declaration(file_path, type_name) {
+            Some(Declaration::Type { node }) => {
+                add_param_group_members(
+                    &param_group.operation,
+                    &param_group.location,
+                    node,
+                    file_path,
+                    &PathOptions::default(),
+                    store,
+                );
+            }
+            Some(Declaration::Import {
+                name: imported_name,
+                source_file_name: module_file_name,
+            }) => {
+                store.defer_param_group(
+                    &module_file_name,
+                    &param_group.operation,
+                    &imported_name,
+                    &param_group.location,
+                );
+            }
+            _ => {}
+        }
     }
 
     if let Some(deferred_operation_type) = store.recognize_operation_type(type_name, file_path) {
@@ -494,16 +1250,20 @@ fn define_external_schema_maybe(open_api: &mut OpenApi, type_name: &str, file_pa
     }
 
     if let Some(deferred_type) = store.recognize_external_type(type_name, file_path) {
+        if open_api.components.contains_schema(&deferred_type.schema_name) {
+            return;
+        }
+
         match store.get_root_declaration(file_path, &type_name) {
-            Some(Declaration::Type { node }) => {
-                let schema = open_api.components.schema_with_id(&deferred_type.schema_name);
+            Some(Declaration::Type { node }) | Some(Declaration::Value { node }) => {
+                let schema = open_api.components.schema(&deferred_type.schema_name);
                 define_schema_details(schema, &node, file_path, false, &PathOptions::default(), store);
             }
             Some(Declaration::Import {
                 name: imported_name,
                 source_file_name: module_file_name,
             }) => {
-                store.defer_external_type(&module_file_name, type_name, &imported_name);
+                store.defer_external_type(&module_file_name, &deferred_type.schema_name, &imported_name);
             }
             _ => {}
         }
@@ -519,6 +1279,15 @@ fn define_schema_details(
     store: &mut Store,
 ) -> () {
     match root.kind {
+        NodeKind::Ident(raw_ident) if is_value_reference(root) => define_schema_from_value(
+            &raw_ident.sym,
+            root_schema,
+            file_path,
+            path_options,
+            is_required,
+            root,
+            store,
+        ),
         NodeKind::Ident(raw_ident) => define_schema_from_identifier(
             &raw_ident.sym,
             root_schema,
@@ -534,34 +1303,62 @@ fn define_schema_details(
             }
         }
         NodeKind::TsUnionType(_) => {
-            let any_of = root_schema.any_of();
-            let mut enum_schema = ApiSchema::new();
+            let mut any_of = vec![];
+            let mut members = vec![];
+            let mut enum_values = vec![];
             for child in root.children() {
                 match child.kind {
                     NodeKind::TsLitType(raw) => match &raw.lit {
-                        TsLit::Number(raw_num) => enum_schema.enum_value(&format!("{}", &raw_num.value)),
-                        TsLit::Str(raw_str) => enum_schema.enum_value(&format!("{}", &raw_str.value)),
-                        TsLit::Bool(raw_bool) => enum_schema.enum_value(&format!("{}", &raw_bool.value)),
-                        TsLit::BigInt(raw_int) => enum_schema.enum_value(&format!("{}", &raw_int.value)),
-                        _ => {}
+                        TsLit::Number(raw_num) => enum_values.push((format!("{}", &raw_num.value), "number")),
+                        TsLit::Str(raw_str) => enum_values.push((format!("{}", &raw_str.value), "string")),
+                        TsLit::Bool(raw_bool) => enum_values.push((format!("{}", &raw_bool.value), "boolean")),
+                        TsLit::BigInt(raw_int) => enum_values.push((format!("{}", &raw_int.value), "number")),
+                        TsLit::Tpl(_) => {
+                            let mut schema = ApiSchema::new();
+                            define_schema_details(&mut schema, &child, file_path, is_required, path_options, store);
+                            any_of.push(schema);
+                        }
                     },
-                    NodeKind::TsTypeLit(_) => {
-                        let mut schema = ApiSchema::new();
-                        define_schema_details(&mut schema, &child, file_path, is_required, path_options, store);
-                        any_of.push(schema);
-                    }
-                    NodeKind::Ident(_) => {
+                    NodeKind::TsTypeLit(_) | NodeKind::TsTypeRef(_) | NodeKind::Ident(_) => {
                         let mut schema = ApiSchema::new();
                         define_schema_details(&mut schema, &child, file_path, is_required, path_options, store);
                         any_of.push(schema);
+                        members.push(child.clone());
                     }
                     _ => {}
                 }
             }
 
-            if enum_schema.has_enums() {
+            let has_enums = !enum_values.is_empty();
+            if has_enums {
+                let mut enum_schema = ApiSchema::new();
+                for (value, enum_type) in enum_values {
+                    enum_schema.literal_value(&value, enum_type);
+                }
                 any_of.push(enum_schema);
             }
+
+            let discriminator = match has_enums {
+                false => find_discriminator(&members, file_path, store),
+                true => None,
+            };
+
+            match discriminator {
+                Some((property_name, values)) => {
+                    let mapping = values
+                        .into_iter()
+                        .zip(any_of.iter())
+                        .filter_map(|(value, schema)| schema.reference_name().map(|r| (value, r.to_string())))
+                        .collect();
+
+                    root_schema.one_of().extend(any_of);
+                    root_schema.discriminator(&property_name, mapping);
+                }
+                None if !any_of.is_empty() => {
+                    root_schema.any_of().extend(any_of);
+                }
+                None => {}
+            }
         }
         NodeKind::TsIntersectionType(_) => {
             let all_of = root_schema.all_of();
@@ -569,10 +1366,10 @@ fn define_schema_details(
             for child in root.children() {
                 match child.kind {
                     NodeKind::TsLitType(raw) => match &raw.lit {
-                        TsLit::Number(raw_num) => enum_schema.enum_value(&format!("{}", &raw_num.value)),
-                        TsLit::Str(raw_str) => enum_schema.enum_value(&format!("{}", &raw_str.value)),
-                        TsLit::Bool(raw_bool) => enum_schema.enum_value(&format!("{}", &raw_bool.value)),
-                        TsLit::BigInt(raw_int) => enum_schema.enum_value(&format!("{}", &raw_int.value)),
+                        TsLit::Number(raw_num) => enum_schema.literal_value(&format!("{}", &raw_num.value), "number"),
+                        TsLit::Str(raw_str) => enum_schema.literal_value(&raw_str.value, "string"),
+                        TsLit::Bool(raw_bool) => enum_schema.literal_value(&format!("{}", &raw_bool.value), "boolean"),
+                        TsLit::BigInt(raw_int) => enum_schema.literal_value(&format!("{}", &raw_int.value), "number"),
                         _ => {}
                     },
                     NodeKind::TsTypeLit(_) => {
@@ -600,16 +1397,17 @@ fn define_schema_details(
                     let param = params.first().unwrap();
                     define_schema_details(root_schema, &param, file_path, is_required, path_options, store);
                 } else {
+                    let schema_name = store.get_schema_name(file_path, &raw_ident.sym);
                     match store.get_root_declaration(file_path, &raw_ident.sym) {
                         Some(Declaration::Import { name, source_file_name }) => {
-                            store.defer_external_type(&source_file_name, &name, &name);
+                            store.defer_external_type(&source_file_name, &schema_name, &name);
 
-                            root_schema.reference(Some(name), false);
+                            reference_schema(root_schema, schema_name, file_path, root, store);
                         }
                         _ => {
-                            store.defer_local_type(file_path, &raw_ident.sym, &raw_ident.sym, root.clone());
+                            store.defer_local_type(file_path, &schema_name, &raw_ident.sym, root.clone());
 
-                            root_schema.reference(Some(raw_ident.sym.to_string()), false);
+                            reference_schema(root_schema, schema_name, file_path, root, store);
                         }
                     }
                 }
@@ -653,135 +1451,52 @@ fn define_schema_details(
             }
             _ => {}
         },
-        NodeKind::ClassDecl(_) => {
-            root_schema.data_type("object");
+        NodeKind::ClassDecl(_) | NodeKind::ClassExpr(_) => {
             if let Some(class_node) = root.class() {
-                for property in class_node.class_props() {
-                    match property.kind {
-                        NodeKind::ClassProp(raw_prop) => {
-                            let name = match &raw_prop.key {
-                                PropName::Ident(identifier) => Some(identifier.sym.to_string()),
-                                _ => None,
-                            };
+                define_class_schema(root_schema, &class_node, file_path, is_required, path_options, store);
+            }
+        }
+        NodeKind::TsArrayType(_) => {
+            root_schema.data_type("array");
+            let elem_type = root.elem_type().unwrap();
+            define_schema_details(
+                root_schema.items(),
+                &elem_type,
+                file_path,
+                is_required,
+                path_options,
+                store,
+            );
+        }
+        NodeKind::TsInterfaceDecl(_) => {
+            let extends = root.extends();
+            if extends.len().gt(&0) {
+                let all_of = root_schema.all_of();
+                for extend in &extends {
+                    let mut schema = ApiSchema::new();
+                    define_schema_details(&mut schema, &extend, file_path, is_required, path_options, store);
+                    all_of.push(schema);
+                }
+            }
+
+            let root_schema = match extends.len() > 0 {
+                true => {
+                    let all_of = root_schema.all_of();
+                    all_of.push(ApiSchema::new());
+                    all_of.last_mut().unwrap()
+                }
+                false => root_schema,
+            };
 
-                            if let Some(name) = name {
-                                if let Some(annotation) = property.type_ann() {
-                                    let property_schema = root_schema.property(&name);
-
-                                    if is_required {
-                                        property_schema.required_field(&name);
-                                    }
-
-                                    define_schema_details(
-                                        property_schema,
-                                        &annotation,
-                                        file_path,
-                                        is_required,
-                                        path_options,
-                                        store,
-                                    );
-                                }
-                            }
-                        }
-                        NodeKind::BindingIdent(raw_ident) => {
-                            if let Some(annotation) = property.type_ann() {
-                                let property_schema = root_schema.property(&raw_ident.sym.to_string());
-
-                                if is_required {
-                                    property_schema.required_field(&raw_ident.sym);
-                                }
-
-                                define_schema_details(
-                                    property_schema,
-                                    &annotation,
-                                    file_path,
-                                    is_required,
-                                    path_options,
-                                    store,
-                                );
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        }
-        NodeKind::ClassExpr(_) => {
-            root_schema.data_type("object");
-            if let Some(class_node) = root.class() {
-                for class_member in class_node.members() {
-                    match class_member.kind {
-                        NodeKind::ClassProp(raw) => {
-                            let name = match &raw.key {
-                                PropName::Ident(identifier) => Some(identifier.sym.to_string()),
-                                _ => None,
-                            };
-
-                            if let Some(name) = name {
-                                if let Some(annotation) = class_member.type_ann() {
-                                    let property_schema = root_schema.property(&name);
-
-                                    if is_required {
-                                        property_schema.required_field(&name);
-                                    }
-
-                                    define_schema_details(
-                                        property_schema,
-                                        &annotation,
-                                        file_path,
-                                        is_required,
-                                        path_options,
-                                        store,
-                                    );
-                                }
-                            }
-                        }
-                        _ => {}
-                    }
-                }
-            }
-        }
-        NodeKind::TsArrayType(_) => {
-            root_schema.data_type("array");
-            let elem_type = root.elem_type().unwrap();
-            define_schema_details(
-                root_schema.items(),
-                &elem_type,
-                file_path,
-                is_required,
-                path_options,
-                store,
-            );
-        }
-        NodeKind::TsInterfaceDecl(_) => {
-            let extends = root.extends();
-            if extends.len().gt(&0) {
-                let all_of = root_schema.all_of();
-                for extend in &extends {
-                    let mut schema = ApiSchema::new();
-                    define_schema_details(&mut schema, &extend, file_path, is_required, path_options, store);
-                    all_of.push(schema);
-                }
-            }
-
-            let root_schema = match extends.len() > 0 {
-                true => {
-                    let all_of = root_schema.all_of();
-                    all_of.push(ApiSchema::new());
-                    all_of.last_mut().unwrap()
-                }
-                false => root_schema,
-            };
-
-            root_schema.data_type("object");
-
-            if let Some(interface_body) = root.interface_body() {
-                for interface_member in interface_body.members() {
-                    match interface_member.kind {
-                        NodeKind::TsTypeElement(TsTypeElement::TsPropertySignature(raw_prop)) => {
-                            let property_schema = match &*raw_prop.key {
-                                Expr::Ident(identifier) => {
-                                    let name = identifier.sym.to_string();
+            root_schema.data_type("object");
+
+            if let Some(interface_body) = root.interface_body() {
+                for interface_member in interface_body.members() {
+                    match interface_member.kind {
+                        NodeKind::TsTypeElement(TsTypeElement::TsPropertySignature(raw_prop)) => {
+                            let property_schema = match &*raw_prop.key {
+                                Expr::Ident(identifier) => {
+                                    let name = identifier.sym.to_string();
                                     let property_schema = root_schema.property(&name);
 
                                     if is_required {
@@ -883,6 +1598,149 @@ fn define_schema_details(
             },
             None => {}
         },
+        NodeKind::ObjectLit(raw) => define_object_schema(root_schema, root, raw, file_path, path_options, store),
+        NodeKind::ArrayLit(raw) => {
+            let mut items = vec![];
+            for element in raw.elems.iter().flatten() {
+                if element.spread.is_none() {
+                    let mut schema = ApiSchema::new();
+                    let element = root.to_child(NodeKind::Expr(&element.expr));
+                    define_schema_details(&mut schema, &element, file_path, true, path_options, store);
+                    items.push(schema);
+                }
+            }
+
+            let root_schema = root_schema.data_type("array");
+            if !items.is_empty() {
+                root_schema.items().union(items);
+            }
+        }
+        NodeKind::CondExpr(raw) => {
+            let mut schemas = vec![];
+            for branch in [&raw.cons, &raw.alt] {
+                let mut schema = ApiSchema::new();
+                match &**branch {
+                    Expr::Lit(Lit::Null(_)) => {
+                        schema.data_type("null");
+                    }
+                    _ => {
+                        let branch = root.to_child(NodeKind::Expr(branch));
+                        define_schema_details(&mut schema, &branch, file_path, is_required, path_options, store);
+                    }
+                }
+                schemas.push(schema);
+            }
+
+            root_schema.union(schemas);
+        }
+        NodeKind::ParenExpr(raw) => {
+            let expr = root.to_child(NodeKind::Expr(&raw.expr));
+            define_schema_details(root_schema, &expr, file_path, is_required, path_options, store);
+        }
+        NodeKind::AwaitExpr(raw) => {
+            let expr = root.to_child(NodeKind::Expr(&raw.arg));
+            define_schema_details(root_schema, &expr, file_path, is_required, path_options, store);
+        }
+        NodeKind::TsNonNullExpr(raw) => {
+            let expr = root.to_child(NodeKind::Expr(&raw.expr));
+            define_schema_details(root_schema, &expr, file_path, is_required, path_options, store);
+        }
+        NodeKind::TsConstAssertionExpr(raw) => {
+            let expr = root.to_child(NodeKind::Expr(&raw.expr));
+            define_schema_details(root_schema, &expr, file_path, is_required, path_options, store);
+        }
+        NodeKind::TsSatisfiesExpr(raw) => {
+            let type_ann = root.to_child(NodeKind::TsType(&raw.type_ann));
+            define_schema_details(root_schema, &type_ann, file_path, is_required, path_options, store);
+        }
+        NodeKind::TsTypeAssertionExpr(raw) => {
+            let type_ann = root.to_child(NodeKind::TsType(&raw.type_ann));
+            define_schema_details(root_schema, &type_ann, file_path, is_required, path_options, store);
+        }
+        NodeKind::NewExpr(raw) => {
+            let callee = root.to_child(NodeKind::Expr(&raw.callee));
+            define_schema_details(root_schema, &callee, file_path, is_required, path_options, store);
+        }
+        NodeKind::CallExpr(raw) => {
+            if begin_call_evaluation(root, raw, file_path, store) {
+                if let Some((return_type, file_path)) = find_return_type(root, raw, file_path, store) {
+                    define_schema_details(root_schema, &return_type, &file_path, is_required, path_options, store);
+                }
+
+                store.end_evaluation();
+            }
+        }
+        NodeKind::MemberExpr(raw) => {
+            if begin_type_evaluation(root, raw.span, file_path, store) {
+                if let Some((member, file_path)) = find_member_type(root, raw, file_path, store) {
+                    define_schema_details(root_schema, &member, &file_path, is_required, path_options, store);
+                }
+
+                store.end_evaluation();
+            }
+        }
+        NodeKind::TsTypeQuery(raw) => match &raw.expr_name {
+            TsTypeQueryExpr::TsEntityName(TsEntityName::Ident(identifier)) => define_schema_from_value(
+                &identifier.sym,
+                root_schema,
+                file_path,
+                path_options,
+                is_required,
+                root,
+                store,
+            ),
+            _ => {
+                if let Some((value, file_path)) = resolve_type(root.clone(), file_path, store) {
+                    define_schema_details(root_schema, &value, &file_path, is_required, path_options, store);
+                }
+            }
+        },
+        NodeKind::TsTypeOperator(raw) => match raw.op {
+            TsTypeOperatorOp::KeyOf => {
+                root_schema.data_type("string");
+                for key in get_key_names(root.clone(), file_path, store) {
+                    root_schema.enum_value(&key);
+                }
+            }
+            _ => {
+                let type_ann = root.to_child(NodeKind::TsType(&raw.type_ann));
+                define_schema_details(root_schema, &type_ann, file_path, is_required, path_options, store);
+            }
+        },
+        NodeKind::TsParenthesizedType(raw) => {
+            let type_ann = root.to_child(NodeKind::TsType(&raw.type_ann));
+            define_schema_details(root_schema, &type_ann, file_path, is_required, path_options, store);
+        }
+        NodeKind::TsTupleType(raw) => {
+            let elements = get_tuple_elements(root, raw);
+            define_tuple_schema(root_schema, elements, file_path, is_required, path_options, store);
+        }
+        NodeKind::TsIndexedAccessType(raw) => {
+            if begin_type_evaluation(root, raw.span, file_path, store) {
+                let (members, is_const) = find_indexed_members(root, raw, file_path, store);
+                define_members_schema(root_schema, members, is_const, is_required, path_options, store);
+                store.end_evaluation();
+            }
+        }
+        NodeKind::TsMappedType(raw) => {
+            define_mapped_schema(root_schema, root, raw, file_path, is_required, path_options, store)
+        }
+        NodeKind::TsConditionalType(raw) => {
+            define_conditional_schema(root_schema, root, raw, file_path, is_required, path_options, store)
+        }
+        NodeKind::TsTplLit(raw) => {
+            root_schema.data_type("string");
+            match join_template_segments(get_template_segments(root, raw, file_path, store)) {
+                TemplateSegment::Literals(values) => {
+                    for value in values {
+                        root_schema.enum_value(&value);
+                    }
+                }
+                TemplateSegment::Pattern(pattern) => {
+                    root_schema.pattern(Some(format!("^{}$", pattern)));
+                }
+            }
+        }
         NodeKind::TemplateLiteral(_) => {
             root_schema.data_type("string");
         }
@@ -909,6 +1767,43 @@ fn define_schema_details(
     }
 }
 
+fn is_value_reference(root: &Rc<SchemyNode<'static>>) -> bool {
+    match root.parent() {
+        Some(parent) => matches!(
+            parent.kind,
+            NodeKind::Expr(_) | NodeKind::ExprOrSpread(_) | NodeKind::ObjectLit(_)
+        ),
+        None => false,
+    }
+}
+
+// values and types live in separate namespaces, so `typeof X` and identifiers in expressions
+// prefer the value named X over a type alias or interface that shares its name
+fn define_schema_from_value(
+    identifier: &str,
+    root_schema: &mut ApiSchema,
+    file_path: &str,
+    path_options: &PathOptions,
+    is_required: bool,
+    root: &Rc<SchemyNode<'static>>,
+    store: &mut Store,
+) -> () {
+    match store.get_root_value_declaration(file_path, identifier) {
+        Some(Declaration::Value { node }) => {
+            define_schema_details(root_schema, &node, file_path, is_required, path_options, store)
+        }
+        _ => define_schema_from_identifier(
+            identifier,
+            root_schema,
+            file_path,
+            path_options,
+            is_required,
+            root,
+            store,
+        ),
+    }
+}
+
 fn define_schema_from_identifier(
     identifier: &str,
     root_schema: &mut ApiSchema,
@@ -918,6 +1813,10 @@ fn define_schema_from_identifier(
     root: &Rc<SchemyNode<'static>>,
     store: &mut Store,
 ) -> () {
+    if let Some((argument, depth)) = store.get_type_argument(identifier) {
+        return define_type_argument(root_schema, argument, depth, is_required, path_options, store);
+    }
+
     let identifier = store.get_root_declaration_name(file_path, identifier);
     match store.get_root_declaration(file_path, &identifier) {
         Some(Declaration::Import { name, source_file_name }) => {
@@ -929,11 +1828,40 @@ fn define_schema_from_identifier(
                 let params = root.params();
                 let param = params.last().unwrap();
                 define_schema_details(root_schema, &param.clone(), file_path, true, path_options, store);
+            } else if identifier.eq("LilSchema") {
+                let params = root.params();
+                let param = params.first().unwrap();
+                define_schema_details(root_schema, &param.clone(), file_path, is_required, path_options, store);
+            } else if find_generic_alias(&identifier, root, file_path, store).is_some() {
+                define_generic_alias(
+                    &identifier,
+                    root_schema,
+                    root,
+                    file_path,
+                    is_required,
+                    path_options,
+                    store,
+                );
             } else {
-                root_schema.reference(Some(identifier.to_string()), false);
-                store.defer_external_type(&source_file_name, &identifier, &name);
+                let schema_name = store.get_schema_name(file_path, &identifier);
+                store.defer_external_type(&source_file_name, &schema_name, &name);
+                reference_schema(root_schema, schema_name, file_path, root, store);
             }
         }
+        Some(Declaration::Value { node }) => {
+            define_schema_details(root_schema, &node, file_path, is_required, path_options, store);
+        }
+        Some(Declaration::Type { .. }) if find_generic_alias(&identifier, root, file_path, store).is_some() => {
+            define_generic_alias(
+                &identifier,
+                root_schema,
+                root,
+                file_path,
+                is_required,
+                path_options,
+                store,
+            );
+        }
         Some(Declaration::Type { node: root }) => {
             if identifier.eq("LilSub") {
                 let params = root.params();
@@ -944,8 +1872,9 @@ fn define_schema_from_identifier(
                 let param = params.first().unwrap();
                 define_schema_details(root_schema, &param.clone(), file_path, true, path_options, store);
             } else {
-                store.defer_local_type(file_path, &identifier, &identifier, root.clone());
-                root_schema.reference(Some(identifier), false);
+                let schema_name = store.get_schema_name(file_path, &identifier);
+                store.defer_local_type(file_path, &schema_name, &identifier, root.clone());
+                reference_schema(root_schema, schema_name, file_path, &root, store);
             }
         }
         _ => {
@@ -955,61 +1884,2033 @@ fn define_schema_from_identifier(
                 if let Some(type_param) = type_params.first() {
                     define_schema_details(items_schema, &type_param, file_path, is_required, path_options, store);
                 }
+            } else if identifier.eq("Promise") || identifier.eq("Awaited") {
+                let type_params = root.type_params();
+                if let Some(type_param) = type_params.first() {
+                    define_schema_details(root_schema, &type_param, file_path, is_required, path_options, store);
+                }
             } else if identifier.eq("Uint8Array") | identifier.eq("Buffer") {
                 root_schema.data_type("string").format(Some("binary".into()));
             } else if identifier.eq("URL") {
                 root_schema.data_type("string").format(Some("uri".into()));
+            } else if identifier.eq("ReturnType") || identifier.eq("Parameters") {
+                define_function_schema(
+                    &identifier,
+                    root_schema,
+                    root,
+                    file_path,
+                    is_required,
+                    path_options,
+                    store,
+                );
+            } else if identifier.eq("InstanceType") {
+                define_instance_schema(root_schema, root, file_path, is_required, path_options, store);
+            } else if identifier.eq("NonNullable") || identifier.eq("Exclude") || identifier.eq("Extract") {
+                define_filtered_members(
+                    &identifier,
+                    root_schema,
+                    root,
+                    file_path,
+                    is_required,
+                    path_options,
+                    store,
+                );
             } else {
-                store.defer_local_type(file_path, &identifier, &identifier, root.clone());
-                root_schema.reference(Some(identifier), false);
+                let schema_name = store.get_schema_name(file_path, &identifier);
+                store.defer_local_type(file_path, &schema_name, &identifier, root.clone());
+                reference_schema(root_schema, schema_name, file_path, root, store);
             }
         }
     }
 }
 
-fn get_path_options(options: Rc<SchemyNode>) -> PathOptions {
-    let mut path_options = PathOptions::new();
-    load_options(&mut path_options, options);
-    path_options
+fn reference_schema(
+    root_schema: &mut ApiSchema,
+    schema_name: String,
+    file_path: &str,
+    root: &Rc<SchemyNode<'static>>,
+    store: &mut Store,
+) -> () {
+    store.track_reference(&schema_name, file_path, root);
+    root_schema.reference(Some(schema_name), false);
 }
 
-fn load_options(path_options: &mut PathOptions, root: Rc<SchemyNode>) {
-    if let NodeKind::ExprOrSpread(raw_expr) = root.kind {
-        match &*raw_expr.expr {
-            Expr::Object(raw_literal) => {
-                for prop_or_spread in &raw_literal.props {
-                    match prop_or_spread.as_prop() {
-                        Some(prop) => match prop.as_ref() {
-                            Prop::KeyValue(key_value) => match &key_value.key {
-                                PropName::Ident(i) if i.sym.eq("method") => {
-                                    path_options.method = match key_value.value.deref() {
-                                        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
-                                        _ => None,
-                                    }
-                                }
-                                PropName::Ident(i) if i.sym.eq("path") => {
-                                    path_options.path = match key_value.value.deref() {
-                                        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
-                                        _ => None,
-                                    }
-                                }
-                                PropName::Ident(i) if i.sym.eq("tags") => {
-                                    let mut tags = vec![];
-                                    match key_value.value.deref() {
-                                        Expr::Array(literal) => {
-                                            for element in &literal.elems {
-                                                if let Some(element) = element {
-                                                    match element.expr.deref() {
-                                                        Expr::Lit(Lit::Str(s)) => tags.push(s.value.to_string()),
-                                                        _ => {}
-                                                    }
-                                                }
-                                            }
-                                        }
-                                        _ => {}
-                                    }
+fn define_object_schema(
+    root_schema: &mut ApiSchema,
+    root: &Rc<SchemyNode<'static>>,
+    object: &'static ObjectLit,
+    file_path: &str,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    root_schema.data_type("object");
+    for prop in &object.props {
+        match prop {
+            PropOrSpread::Spread(raw_spread) => {
+                let mut schema = ApiSchema::new();
+                let spread = root.to_child(NodeKind::Expr(&raw_spread.expr));
+                define_schema_details(&mut schema, &spread, file_path, true, path_options, store);
+                root_schema.all_of().push(schema);
+            }
+            PropOrSpread::Prop(raw_prop) => {
+                let (name, value) = match &**raw_prop {
+                    Prop::KeyValue(raw) => match get_prop_name(&raw.key) {
+                        Some(name) => (name, root.to_child(NodeKind::Expr(&raw.value))),
+                        None => continue,
+                    },
+                    Prop::Shorthand(raw) => (raw.sym.to_string(), root.to_child(NodeKind::Ident(raw))),
+                    Prop::Getter(raw) => match (get_prop_name(&raw.key), &raw.type_ann) {
+                        (Some(name), Some(type_ann)) => (name, root.to_child(NodeKind::TsTypeAnnotation(type_ann))),
+                        _ => continue,
+                    },
+                    _ => continue,
+                };
 
-                                    if tags.len() > 0 {
+                root_schema.required_field(&name);
+                let property_schema = root_schema.property(&name);
+                define_schema_details(property_schema, &value, file_path, true, path_options, store);
+            }
+        }
+    }
+}
+
+fn define_class_schema(
+    root_schema: &mut ApiSchema,
+    class_node: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let class = match class_node.kind {
+        NodeKind::Class(raw) => raw,
+        _ => return,
+    };
+
+    let root_schema = match define_base_class_schema(class_node, class, file_path, is_required, path_options, store) {
+        Some(base_schema) => {
+            let all_of = root_schema.all_of();
+            all_of.push(base_schema);
+            all_of.push(ApiSchema::new());
+            all_of.last_mut().unwrap()
+        }
+        None => root_schema,
+    };
+
+    root_schema.data_type("object");
+
+    for property in get_class_properties(class) {
+        let annotation = match property.annotation {
+            Some(annotation) => class_node.to_child(NodeKind::TsTypeAnnotation(annotation)),
+            None => continue,
+        };
+
+        if is_required && !property.is_optional {
+            root_schema.required_field(&property.name);
+        }
+
+        let property_schema = root_schema.property(&property.name);
+        define_schema_details(
+            property_schema,
+            &annotation,
+            file_path,
+            is_required,
+            path_options,
+            store,
+        );
+
+        if property.is_read_only {
+            property_schema.read_only(true);
+        }
+    }
+}
+
+// a base class is referenced by name, unless it is given type arguments, in which case it is defined in place
+// with its type parameters bound to them
+fn define_base_class_schema(
+    class_node: &Rc<SchemyNode<'static>>,
+    class: &'static Class,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> Option<ApiSchema> {
+    let super_class = class.super_class.as_deref()?;
+    let identifier = match super_class {
+        Expr::Ident(identifier) => identifier,
+        _ => {
+            let message = "Could not evaluate the base class, only its own properties are included";
+            store.add_diagnostic(Severity::Warning, message, file_path, Some(class_node));
+            return None;
+        }
+    };
+
+    let mut base_schema = ApiSchema::new();
+    let type_args: Vec<Rc<SchemyNode<'static>>> = class
+        .super_type_params
+        .iter()
+        .flat_map(|type_params| &type_params.params)
+        .map(|type_param| class_node.to_child(NodeKind::TsType(type_param)))
+        .collect();
+    if type_args.is_empty() {
+        let super_class = class_node.to_child(NodeKind::Expr(super_class));
+        define_schema_from_identifier(
+            &identifier.sym,
+            &mut base_schema,
+            file_path,
+            path_options,
+            is_required,
+            &super_class,
+            store,
+        );
+        return Some(base_schema);
+    }
+
+    let base_class = resolve_base_class(class_node, file_path, store)
+        .and_then(|(base_class, base_file_path)| Some((base_class.class()?, base_file_path)));
+    let (base_class, base_file_path, raw_base) = match base_class {
+        Some((base_class, base_file_path)) => match base_class.kind {
+            NodeKind::Class(raw_base) => (base_class, base_file_path, raw_base),
+            _ => return None,
+        },
+        None => {
+            let message = "Could not evaluate the base class, only its own properties are included";
+            store.add_diagnostic(Severity::Warning, message, file_path, Some(class_node));
+            return None;
+        }
+    };
+
+    let arguments = get_type_arguments(
+        raw_base.type_params.as_deref(),
+        type_args,
+        file_path,
+        &base_class,
+        &base_file_path,
+    );
+    if !store.bind_type_arguments(arguments) {
+        let message = format!(
+            "Could not evaluate '{}', its type arguments are nested too deeply",
+            identifier.sym
+        );
+        store.add_diagnostic(Severity::Warning, &message, file_path, Some(class_node));
+        return None;
+    }
+
+    define_class_schema(
+        &mut base_schema,
+        &base_class,
+        &base_file_path,
+        is_required,
+        path_options,
+        store,
+    );
+    store.unbind_type_arguments();
+    Some(base_schema)
+}
+
+struct ClassProperty {
+    name: String,
+    annotation: Option<&'static TsTypeAnn>,
+    is_optional: bool,
+    is_read_only: bool,
+}
+
+// public instance fields, constructor parameter properties and getters, in declaration order
+fn get_class_properties(class: &'static Class) -> Vec<ClassProperty> {
+    let setters: Vec<String> = class
+        .body
+        .iter()
+        .filter_map(|member| match member {
+            ClassMember::Method(raw) if raw.kind == MethodKind::Setter && !raw.is_static => get_prop_name(&raw.key),
+            _ => None,
+        })
+        .collect();
+
+    let mut properties = vec![];
+    for member in &class.body {
+        match member {
+            ClassMember::Constructor(raw) => {
+                for param in &raw.params {
+                    let param_prop = match param {
+                        ParamOrTsParamProp::TsParamProp(param_prop) if is_public(param_prop.accessibility) => {
+                            param_prop
+                        }
+                        _ => continue,
+                    };
+
+                    let (binding, has_default) = match &param_prop.param {
+                        TsParamPropParam::Ident(binding) => (binding, false),
+                        TsParamPropParam::Assign(raw_assign) => match &*raw_assign.left {
+                            Pat::Ident(binding) => (binding, true),
+                            _ => continue,
+                        },
+                    };
+
+                    properties.push(ClassProperty {
+                        name: binding.id.sym.to_string(),
+                        annotation: binding.type_ann.as_deref(),
+                        is_optional: binding.id.optional || has_default,
+                        is_read_only: param_prop.readonly,
+                    });
+                }
+            }
+            ClassMember::ClassProp(raw) if !raw.is_static && is_public(raw.accessibility) => {
+                if let Some(name) = get_prop_name(&raw.key) {
+                    properties.push(ClassProperty {
+                        name,
+                        annotation: raw.type_ann.as_deref(),
+                        is_optional: raw.is_optional,
+                        is_read_only: raw.readonly,
+                    });
+                }
+            }
+            ClassMember::Method(raw)
+                if raw.kind == MethodKind::Getter && !raw.is_static && is_public(raw.accessibility) =>
+            {
+                if let Some(name) = get_prop_name(&raw.key) {
+                    properties.push(ClassProperty {
+                        is_read_only: !setters.contains(&name),
+                        name,
+                        annotation: raw.function.return_type.as_deref(),
+                        is_optional: raw.is_optional,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    properties
+}
+
+// the keys of a class instance as `keyof` sees them: public fields, parameter properties, accessors and methods
+fn get_class_keys(class: &'static Class) -> Vec<String> {
+    let mut keys = vec![];
+    for member in &class.body {
+        let names: Vec<String> = match member {
+            ClassMember::Constructor(raw) => raw
+                .params
+                .iter()
+                .filter_map(|param| match param {
+                    ParamOrTsParamProp::TsParamProp(param_prop) if is_public(param_prop.accessibility) => {
+                        match &param_prop.param {
+                            TsParamPropParam::Ident(binding) => Some(binding.id.sym.to_string()),
+                            TsParamPropParam::Assign(raw_assign) => match &*raw_assign.left {
+                                Pat::Ident(binding) => Some(binding.id.sym.to_string()),
+                                _ => None,
+                            },
+                        }
+                    }
+                    _ => None,
+                })
+                .collect(),
+            ClassMember::ClassProp(raw) if !raw.is_static && is_public(raw.accessibility) => {
+                get_prop_name(&raw.key).into_iter().collect()
+            }
+            ClassMember::Method(raw) if !raw.is_static && is_public(raw.accessibility) => {
+                get_prop_name(&raw.key).into_iter().collect()
+            }
+            _ => vec![],
+        };
+
+        for name in names {
+            if !keys.contains(&name) {
+                keys.push(name);
+            }
+        }
+    }
+
+    keys
+}
+
+fn is_public(accessibility: Option<Accessibility>) -> bool {
+    !matches!(accessibility, Some(Accessibility::Private | Accessibility::Protected))
+}
+
+fn find_discriminator(
+    members: &Vec<Rc<SchemyNode<'static>>>,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(String, Vec<String>)> {
+    if members.len() < 2 {
+        return None;
+    }
+
+    let mut resolved = vec![];
+    for member in members {
+        resolved.push(resolve_type(member.clone(), file_path, store)?);
+    }
+
+    let (first, first_file_path) = resolved.first()?.clone();
+    for property_name in get_property_names(&first, &first_file_path, store) {
+        let mut values = vec![];
+        for (member, member_file_path) in &resolved {
+            let value = find_member(member.clone(), &property_name, member_file_path, store)
+                .and_then(|(annotation, _)| get_literal_value(&annotation));
+
+            match value {
+                Some(value) if !values.contains(&value) => values.push(value),
+                _ => break,
+            }
+        }
+
+        if values.len() == resolved.len() {
+            return Some((property_name, values));
+        }
+    }
+
+    None
+}
+
+fn get_property_names(root: &Rc<SchemyNode<'static>>, file_path: &str, store: &mut Store) -> Vec<String> {
+    let mut names = vec![];
+    let members = match root.kind {
+        NodeKind::ObjectLit(raw) => {
+            for prop in &raw.props {
+                let name = match prop {
+                    PropOrSpread::Prop(raw_prop) => match &**raw_prop {
+                        Prop::KeyValue(raw) => get_prop_name(&raw.key),
+                        Prop::Shorthand(raw) => Some(raw.sym.to_string()),
+                        Prop::Getter(raw) => get_prop_name(&raw.key),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                names.extend(name);
+            }
+
+            vec![]
+        }
+        NodeKind::TsConstAssertionExpr(raw) => {
+            names.extend(
+                get_asserted_value(root, raw)
+                    .map(|value| get_property_names(&value, file_path, store))
+                    .unwrap_or_default(),
+            );
+            vec![]
+        }
+        NodeKind::TsTypeLit(_) => root.members(),
+        NodeKind::TsInterfaceDecl(_) => root.interface_body().map(|body| body.members()).unwrap_or_default(),
+        NodeKind::ClassDecl(raw) => {
+            // properties inherited from base classes come first, as they do in the class schema
+            if let Some((base_class, base_file_path)) = resolve_base_class(root, file_path, store) {
+                names.extend(get_property_names(&base_class, &base_file_path, store));
+            }
+
+            names.extend(get_class_keys(&raw.class));
+            vec![]
+        }
+        _ => vec![],
+    };
+
+    for member in members {
+        match member.kind {
+            NodeKind::TsTypeElement(TsTypeElement::TsPropertySignature(raw_prop)) => match &*raw_prop.key {
+                Expr::Ident(identifier) => names.push(identifier.sym.to_string()),
+                _ => {}
+            },
+            NodeKind::ClassProp(raw_prop) => match &raw_prop.key {
+                PropName::Ident(identifier) => names.push(identifier.sym.to_string()),
+                _ => {}
+            },
+            NodeKind::BindingIdent(raw_ident) => names.push(raw_ident.sym.to_string()),
+            _ => {}
+        }
+    }
+
+    names
+}
+
+fn get_literal_value(root: &Rc<SchemyNode<'static>>) -> Option<String> {
+    match root.kind {
+        NodeKind::TsTypeAnnotation(_) | NodeKind::TsType(_) => root
+            .children()
+            .into_iter()
+            .next()
+            .and_then(|child| get_literal_value(&child)),
+        NodeKind::TsLitType(raw) => match &raw.lit {
+            TsLit::Str(raw_str) => Some(raw_str.value.to_string()),
+            TsLit::Number(raw_num) => Some(format!("{}", raw_num.value)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// values only keep their literal types under an `as const` assertion, otherwise they widen to their primitive
+fn get_literal(root: &Rc<SchemyNode<'static>>, is_const: bool) -> Option<(String, &'static str)> {
+    match root.kind {
+        NodeKind::TsTypeAnnotation(_) | NodeKind::TsType(_) => root
+            .children()
+            .into_iter()
+            .next()
+            .and_then(|child| get_literal(&child, is_const)),
+        NodeKind::Expr(_) | NodeKind::Lit(_) if is_const => root
+            .children()
+            .into_iter()
+            .next()
+            .and_then(|child| get_literal(&child, is_const)),
+        NodeKind::TsLitType(raw) => match &raw.lit {
+            TsLit::Str(raw_str) => Some((raw_str.value.to_string(), "string")),
+            TsLit::Number(raw_num) => Some((format!("{}", raw_num.value), "number")),
+            TsLit::Bool(raw_bool) => Some((format!("{}", raw_bool.value), "boolean")),
+            _ => None,
+        },
+        NodeKind::Str(raw) if is_const => Some((raw.value.to_string(), "string")),
+        NodeKind::Num(raw) if is_const => Some((format!("{}", raw.value), "number")),
+        NodeKind::Bool(raw) if is_const => Some((format!("{}", raw.value), "boolean")),
+        _ => None,
+    }
+}
+
+// the keys named by an index type: literals, unions of literals, keyof and aliases of those
+fn get_key_names(root: Rc<SchemyNode<'static>>, file_path: &str, store: &mut Store) -> Vec<String> {
+    match root.kind {
+        NodeKind::TsType(_) | NodeKind::TsTypeAnnotation(_) | NodeKind::TsUnionOrIntersectionType(_) => {
+            match root.children().into_iter().next() {
+                Some(child) => get_key_names(child, file_path, store),
+                None => vec![],
+            }
+        }
+        NodeKind::TsLitType(raw) => match &raw.lit {
+            TsLit::Str(raw_str) => vec![raw_str.value.to_string()],
+            TsLit::Number(raw_num) => vec![format!("{}", raw_num.value)],
+            _ => vec![],
+        },
+        NodeKind::TsUnionType(_) => root
+            .children()
+            .into_iter()
+            .flat_map(|child| get_key_names(child, file_path, store))
+            .collect(),
+        NodeKind::TsParenthesizedType(raw) => {
+            get_key_names(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsTypeOperator(raw) if raw.op == TsTypeOperatorOp::KeyOf => {
+            match resolve_type(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store) {
+                Some((object, object_file_path)) => get_property_names(&object, &object_file_path, store),
+                None => vec![],
+            }
+        }
+        NodeKind::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(identifier),
+            ..
+        }) if store.get_type_argument(&identifier.sym).is_some() => match store.get_type_argument(&identifier.sym) {
+            Some((TypeArgument::Type { node, file_path }, depth)) => {
+                let scopes = store.suspend_type_arguments(depth);
+                let names = get_key_names(node, &file_path, store);
+                store.resume_type_arguments(scopes);
+                names
+            }
+            Some((TypeArgument::Key(key), _)) => vec![key],
+            None => vec![],
+        },
+        NodeKind::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(identifier),
+            ..
+        }) if is_filter_utility(&identifier.sym) => filter_union_members(&identifier.sym, &root, file_path, store)
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|(member, member_file_path)| get_key_names(member, &member_file_path, store))
+            .collect(),
+        NodeKind::TsTypeRef(raw) if begin_type_evaluation(&root, raw.span, file_path, store) => {
+            let names = match resolve_type(root.clone(), file_path, store) {
+                Some((resolved, file_path)) => get_key_names(resolved, &file_path, store),
+                None => vec![],
+            };
+
+            store.end_evaluation();
+            names
+        }
+        _ => vec![],
+    }
+}
+
+fn find_indexed_members(
+    root: &Rc<SchemyNode<'static>>,
+    indexed_access: &'static TsIndexedAccessType,
+    file_path: &str,
+    store: &mut Store,
+) -> (Vec<(Rc<SchemyNode<'static>>, String)>, bool) {
+    let object = root.to_child(NodeKind::TsType(&indexed_access.obj_type));
+    let (object, object_file_path) = match resolve_type(object, file_path, store) {
+        Some(object) => object,
+        None => return (vec![], false),
+    };
+
+    let is_const = matches!(object.kind, NodeKind::TsConstAssertionExpr(_));
+    let members = match &*indexed_access.index_type {
+        TsType::TsKeywordType(TsKeywordType {
+            kind: TsKeywordTypeKind::TsNumberKeyword,
+            ..
+        }) => get_elements(&object, &object_file_path),
+        index_type => {
+            let index = root.to_child(NodeKind::TsType(index_type));
+            get_key_names(index, file_path, store)
+                .iter()
+                .filter_map(|name| find_member(object.clone(), name, &object_file_path, store))
+                .collect()
+        }
+    };
+
+    (members, is_const)
+}
+
+fn get_elements(root: &Rc<SchemyNode<'static>>, file_path: &str) -> Vec<(Rc<SchemyNode<'static>>, String)> {
+    match root.kind {
+        NodeKind::TsArrayType(_) => root
+            .elem_type()
+            .map(|elem_type| vec![(elem_type, file_path.to_string())])
+            .unwrap_or_default(),
+        NodeKind::TsTupleType(raw) => raw
+            .elem_types
+            .iter()
+            .map(|element| (root.to_child(NodeKind::TsType(&element.ty)), file_path.to_string()))
+            .collect(),
+        NodeKind::ArrayLit(raw) => raw
+            .elems
+            .iter()
+            .flatten()
+            .filter(|element| element.spread.is_none())
+            .map(|element| (root.to_child(NodeKind::Expr(&element.expr)), file_path.to_string()))
+            .collect(),
+        NodeKind::TsConstAssertionExpr(raw) => match get_asserted_value(root, raw) {
+            Some(value) => get_elements(&value, file_path),
+            None => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+// the array or object literal under an `as const` assertion
+fn get_asserted_value(
+    root: &Rc<SchemyNode<'static>>,
+    raw: &'static TsConstAssertion,
+) -> Option<Rc<SchemyNode<'static>>> {
+    let value = root.to_child(NodeKind::Expr(&raw.expr));
+    let child = value.children().into_iter().next()?;
+    match child.kind {
+        NodeKind::ParenExpr(raw) => {
+            let value = child.to_child(NodeKind::Expr(&raw.expr));
+            value.children().into_iter().next()
+        }
+        _ => Some(child),
+    }
+}
+
+// literals become an enum, typed when they share a type
+fn define_literals_schema(root_schema: &mut ApiSchema, literals: Vec<(String, &str)>) -> () {
+    let mut enum_types = vec![];
+    for (value, enum_type) in literals {
+        root_schema.literal_value(&value, enum_type);
+        enum_types.push(enum_type);
+    }
+
+    enum_types.sort();
+    enum_types.dedup();
+    if enum_types.len() == 1 {
+        root_schema.data_type(enum_types[0]);
+    }
+}
+
+// literal members, as from an `as const` array or object, become an enum
+fn define_members_schema(
+    root_schema: &mut ApiSchema,
+    members: Vec<(Rc<SchemyNode<'static>>, String)>,
+    is_const: bool,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let literals: Vec<(String, &str)> = members
+        .iter()
+        .filter_map(|(member, _)| get_literal(member, is_const))
+        .collect();
+    if !literals.is_empty() && literals.len() == members.len() {
+        define_literals_schema(root_schema, literals);
+    } else if members.len() == 1 {
+        let (member, file_path) = &members[0];
+        define_schema_details(root_schema, member, file_path, is_required, path_options, store);
+    } else {
+        let mut schemas = vec![];
+        for (member, file_path) in members {
+            let mut schema = ApiSchema::new();
+            define_schema_details(&mut schema, &member, &file_path, is_required, path_options, store);
+            schemas.push(schema);
+        }
+
+        root_schema.union(schemas);
+    }
+}
+
+fn define_type_argument(
+    root_schema: &mut ApiSchema,
+    argument: TypeArgument,
+    depth: usize,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    match argument {
+        TypeArgument::Key(key) => {
+            root_schema.data_type("string").enum_value(&key);
+        }
+        TypeArgument::Type { node, file_path } => {
+            let scopes = store.suspend_type_arguments(depth);
+            define_schema_details(root_schema, &node, &file_path, is_required, path_options, store);
+            store.resume_type_arguments(scopes);
+        }
+    }
+}
+
+// a type alias that declares type parameters, when the reference supplies type arguments
+fn find_generic_alias(
+    type_name: &str,
+    root: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    if root.type_params().is_empty() {
+        return None;
+    }
+
+    match store.get_root_declaration(file_path, type_name) {
+        Some(Declaration::Type { node }) => match node.kind {
+            NodeKind::TsTypeAliasDecl(TsTypeAliasDecl {
+                type_params: Some(_), ..
+            }) => Some((node, file_path.to_string())),
+            _ => None,
+        },
+        Some(Declaration::Import { name, source_file_name }) | Some(Declaration::Export { name, source_file_name }) => {
+            store.parse_module(&source_file_name);
+            find_generic_alias(&name, root, &source_file_name, store)
+        }
+        _ => None,
+    }
+}
+
+fn define_generic_alias(
+    type_name: &str,
+    root_schema: &mut ApiSchema,
+    root: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let (alias, alias_file_path) = match find_generic_alias(type_name, root, file_path, store) {
+        Some(alias) => alias,
+        None => return,
+    };
+
+    let raw_alias = match alias.kind {
+        NodeKind::TsTypeAliasDecl(raw_alias) => raw_alias,
+        _ => return,
+    };
+
+    let arguments = get_type_arguments(
+        raw_alias.type_params.as_deref(),
+        root.type_params(),
+        file_path,
+        &alias,
+        &alias_file_path,
+    );
+    if !store.bind_type_arguments(arguments) {
+        let message = format!(
+            "Could not evaluate '{}', its type arguments are nested too deeply",
+            raw_alias.id.sym
+        );
+        store.add_diagnostic(Severity::Warning, &message, file_path, Some(root));
+        return;
+    }
+
+    let type_ann = alias.to_child(NodeKind::TsType(&raw_alias.type_ann));
+    define_schema_details(
+        root_schema,
+        &type_ann,
+        &alias_file_path,
+        is_required,
+        path_options,
+        store,
+    );
+    store.unbind_type_arguments();
+}
+
+// each type parameter of a generic declaration is bound to its type argument, or to its default when none is given
+fn get_type_arguments(
+    type_params: Option<&'static TsTypeParamDecl>,
+    type_args: Vec<Rc<SchemyNode<'static>>>,
+    file_path: &str,
+    declaration: &Rc<SchemyNode<'static>>,
+    declaration_file_path: &str,
+) -> BTreeMap<String, TypeArgument> {
+    let mut arguments = BTreeMap::new();
+    for (index, type_param) in type_params.iter().flat_map(|decl| &decl.params).enumerate() {
+        let argument = match (type_args.get(index), &type_param.default) {
+            (Some(type_arg), _) => TypeArgument::Type {
+                node: type_arg.clone(),
+                file_path: file_path.to_string(),
+            },
+            (None, Some(default)) => TypeArgument::Type {
+                node: declaration.to_child(NodeKind::TsType(default)),
+                file_path: declaration_file_path.to_string(),
+            },
+            (None, None) => continue,
+        };
+
+        arguments.insert(type_param.name.sym.to_string(), argument);
+    }
+
+    arguments
+}
+
+fn define_mapped_schema(
+    root_schema: &mut ApiSchema,
+    root: &Rc<SchemyNode<'static>>,
+    mapped_type: &'static TsMappedType,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let keys = match (&mapped_type.type_param.constraint, &mapped_type.name_type) {
+        (Some(constraint), None) => get_key_names(root.to_child(NodeKind::TsType(constraint)), file_path, store),
+        _ => vec![],
+    };
+
+    if keys.is_empty() {
+        store.add_diagnostic(
+            Severity::Warning,
+            "Could not evaluate the keys of a mapped type",
+            file_path,
+            Some(root),
+        );
+        return;
+    }
+
+    root_schema.data_type("object");
+    for key in keys {
+        let arguments = BTreeMap::from([(
+            mapped_type.type_param.name.sym.to_string(),
+            TypeArgument::Key(key.clone()),
+        )]);
+        if !store.bind_type_arguments(arguments) {
+            return;
+        }
+
+        let property_schema = root_schema.property(&key);
+        if let Some(type_ann) = &mapped_type.type_ann {
+            let type_ann = root.to_child(NodeKind::TsType(type_ann));
+            define_schema_details(property_schema, &type_ann, file_path, is_required, path_options, store);
+        }
+
+        if let Some(TruePlusMinus::True | TruePlusMinus::Plus) = mapped_type.readonly {
+            property_schema.read_only(true);
+        }
+
+        store.unbind_type_arguments();
+
+        match mapped_type.optional {
+            Some(TruePlusMinus::Minus) => {
+                root_schema.required_field(&key);
+            }
+            None if is_required => {
+                root_schema.required_field(&key);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn define_conditional_schema(
+    root_schema: &mut ApiSchema,
+    root: &Rc<SchemyNode<'static>>,
+    conditional_type: &'static TsConditionalType,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    // a bare type parameter distributes the condition over each member of its union
+    let check_param = match &*conditional_type.check_type {
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(identifier),
+            type_params: None,
+            ..
+        }) if store.get_type_argument(&identifier.sym).is_some() => Some(identifier.sym.to_string()),
+        _ => None,
+    };
+
+    let check_type = root.to_child(NodeKind::TsType(&conditional_type.check_type));
+    let extends_type = root.to_child(NodeKind::TsType(&conditional_type.extends_type));
+    let members = match check_param {
+        Some(_) => expand_union_members(check_type, file_path, store),
+        None => vec![(check_type, file_path.to_string())],
+    };
+
+    let mut results = vec![];
+    for (member, member_file_path) in members {
+        let branch = match is_assignable(&member, &member_file_path, &extends_type, file_path, store) {
+            Some(true) => &conditional_type.true_type,
+            Some(false) => &conditional_type.false_type,
+            None => {
+                store.add_diagnostic(
+                    Severity::Warning,
+                    "Could not evaluate a conditional type",
+                    file_path,
+                    Some(root),
+                );
+                return;
+            }
+        };
+
+        match &**branch {
+            TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsNeverKeyword,
+                ..
+            }) => {}
+            TsType::TsTypeRef(TsTypeRef {
+                type_name: TsEntityName::Ident(identifier),
+                type_params: None,
+                ..
+            }) if check_param.as_deref() == Some(&*identifier.sym) => results.push((member, member_file_path, None)),
+            _ => {
+                let branch = root.to_child(NodeKind::TsType(branch));
+                let argument = check_param
+                    .as_ref()
+                    .filter(|_| get_literal(&branch, false).is_none())
+                    .map(|name| {
+                        let argument = TypeArgument::Type {
+                            node: member,
+                            file_path: member_file_path,
+                        };
+
+                        BTreeMap::from([(name.clone(), argument)])
+                    });
+
+                results.push((branch, file_path.to_string(), argument));
+            }
+        }
+    }
+
+    if results.iter().all(|(_, _, argument)| argument.is_none()) {
+        let members = results
+            .into_iter()
+            .map(|(member, file_path, _)| (member, file_path))
+            .collect();
+        define_members_schema(root_schema, members, false, is_required, path_options, store);
+    } else {
+        let mut schemas = vec![];
+        for (member, file_path, argument) in results {
+            let is_bound = match argument {
+                Some(argument) => store.bind_type_arguments(argument),
+                None => false,
+            };
+
+            let mut schema = ApiSchema::new();
+            define_schema_details(&mut schema, &member, &file_path, is_required, path_options, store);
+            schemas.push(schema);
+
+            if is_bound {
+                store.unbind_type_arguments();
+            }
+        }
+
+        root_schema.union(schemas);
+    }
+}
+
+// NonNullable, Exclude and Extract keep the members of a union that pass their condition
+fn define_filtered_members(
+    utility: &str,
+    root_schema: &mut ApiSchema,
+    root: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    match filter_union_members(utility, root, file_path, store) {
+        Some(members) => define_members_schema(root_schema, members, false, is_required, path_options, store),
+        None => {
+            let message = format!("Could not evaluate '{}'", utility);
+            store.add_diagnostic(Severity::Warning, &message, file_path, Some(root));
+        }
+    }
+}
+
+fn is_filter_utility(type_name: &str) -> bool {
+    matches!(type_name, "NonNullable" | "Exclude" | "Extract")
+}
+
+// the members of the first type argument that pass the condition, or None when one of them cannot be evaluated
+fn filter_union_members(
+    utility: &str,
+    root: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<Vec<(Rc<SchemyNode<'static>>, String)>> {
+    let type_params = root.type_params();
+    let union_type = type_params.first()?.clone();
+
+    let mut members = vec![];
+    for (member, member_file_path) in expand_union_members(union_type, file_path, store) {
+        let is_kept = match (utility, type_params.get(1)) {
+            ("NonNullable", _) => Some(!matches!(
+                get_type_fact(member.clone(), &member_file_path, store),
+                TypeFact::Keyword(TsKeywordTypeKind::TsNullKeyword | TsKeywordTypeKind::TsUndefinedKeyword)
+            )),
+            ("Exclude", Some(excluded)) => is_assignable(&member, &member_file_path, excluded, file_path, store)
+                .map(|is_assignable| !is_assignable),
+            ("Extract", Some(extracted)) => is_assignable(&member, &member_file_path, extracted, file_path, store),
+            _ => None,
+        };
+
+        if is_kept? {
+            members.push((member, member_file_path));
+        }
+    }
+
+    Some(members)
+}
+
+// `ReturnType` and `Parameters` read the annotations of the function, method or function type they are given
+fn define_function_schema(
+    utility: &str,
+    root_schema: &mut ApiSchema,
+    root: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let span = match root.kind {
+        NodeKind::TsTypeRef(raw) => raw.span,
+        _ => DUMMY_SP,
+    };
+    if !begin_type_evaluation(root, span, file_path, store) {
+        return;
+    }
+
+    let function = root
+        .type_params()
+        .into_iter()
+        .next()
+        .and_then(|type_param| resolve_type(type_param, file_path, store));
+
+    let (function, function_file_path) = match function {
+        Some(function) if is_function(&function.0) => function,
+        _ => {
+            let message = format!("Could not evaluate '{}'", utility);
+            store.add_diagnostic(Severity::Warning, &message, file_path, Some(root));
+            store.end_evaluation();
+            return;
+        }
+    };
+
+    if utility.eq("Parameters") {
+        let params = get_params(&function);
+        define_tuple_schema(
+            root_schema,
+            params,
+            &function_file_path,
+            is_required,
+            path_options,
+            store,
+        );
+    } else if let Some((return_type, return_file_path)) = get_return_type(&function, &function_file_path) {
+        define_schema_details(
+            root_schema,
+            &return_type,
+            &return_file_path,
+            is_required,
+            path_options,
+            store,
+        );
+    } else if function.return_type().is_none() {
+        let message = format!(
+            "Could not evaluate '{}', the function has no return type annotation",
+            utility
+        );
+        store.add_diagnostic(Severity::Warning, &message, file_path, Some(root));
+    }
+
+    store.end_evaluation();
+}
+
+// tuples and parameter lists become an array whose leading required elements must be present
+fn define_tuple_schema(
+    root_schema: &mut ApiSchema,
+    elements: Vec<(Option<Rc<SchemyNode<'static>>>, ParamKind)>,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let mut prefix_items = vec![];
+    let mut min_items = 0;
+    for (element, kind) in elements {
+        if kind == ParamKind::Rest {
+            let rest = element.and_then(|element| resolve_type(element, file_path, store));
+            let items_schema = root_schema.items();
+            if let Some((rest, rest_file_path)) = rest {
+                if let [(element, element_file_path)] = get_elements(&rest, &rest_file_path).as_slice() {
+                    define_schema_details(
+                        items_schema,
+                        element,
+                        element_file_path,
+                        is_required,
+                        path_options,
+                        store,
+                    );
+                }
+            }
+            continue;
+        }
+
+        if kind == ParamKind::Required {
+            min_items += 1;
+        }
+
+        let mut schema = ApiSchema::new();
+        if let Some(element) = element {
+            define_schema_details(&mut schema, &element, file_path, is_required, path_options, store);
+        }
+        prefix_items.push(schema);
+    }
+
+    root_schema.data_type("array").prefix_items(prefix_items, min_items);
+}
+
+fn define_instance_schema(
+    root_schema: &mut ApiSchema,
+    root: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    is_required: bool,
+    path_options: &PathOptions,
+    store: &mut Store,
+) -> () {
+    let type_param = match root.type_params().into_iter().next() {
+        Some(type_param) => type_param,
+        None => return,
+    };
+
+    let class = resolve_type(type_param.clone(), file_path, store);
+    match (&type_param.kind, class) {
+        (
+            NodeKind::TsTypeQuery(TsTypeQuery {
+                expr_name: TsTypeQueryExpr::TsEntityName(TsEntityName::Ident(identifier)),
+                ..
+            }),
+            Some((class, _)),
+        ) if matches!(class.kind, NodeKind::ClassDecl(_)) => {
+            // named classes are referenced like any other class used as a type
+            define_schema_from_identifier(
+                &identifier.sym,
+                root_schema,
+                file_path,
+                path_options,
+                is_required,
+                root,
+                store,
+            );
+        }
+        (_, Some((class, class_file_path)))
+            if matches!(class.kind, NodeKind::ClassDecl(_) | NodeKind::ClassExpr(_)) =>
+        {
+            define_schema_details(root_schema, &class, &class_file_path, is_required, path_options, store);
+        }
+        _ => {
+            store.add_diagnostic(
+                Severity::Warning,
+                "Could not evaluate 'InstanceType'",
+                file_path,
+                Some(root),
+            );
+        }
+    }
+}
+
+fn expand_union_members(
+    root: Rc<SchemyNode<'static>>,
+    file_path: &str,
+    store: &mut Store,
+) -> Vec<(Rc<SchemyNode<'static>>, String)> {
+    match root.kind {
+        NodeKind::TsType(_) | NodeKind::TsTypeAnnotation(_) | NodeKind::TsUnionOrIntersectionType(_) => {
+            match root.children().into_iter().next() {
+                Some(child) => expand_union_members(child, file_path, store),
+                None => vec![],
+            }
+        }
+        NodeKind::TsParenthesizedType(raw) => {
+            expand_union_members(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsUnionType(_) => root
+            .children()
+            .into_iter()
+            .flat_map(|child| expand_union_members(child, file_path, store))
+            .collect(),
+        // keyof expands into a string literal member for each key
+        NodeKind::TsTypeOperator(raw) if raw.op == TsTypeOperatorOp::KeyOf => {
+            get_key_names(root.clone(), file_path, store)
+                .iter()
+                .map(|key| {
+                    (
+                        root.to_child(NodeKind::TsType(store.get_key_type(key))),
+                        file_path.to_string(),
+                    )
+                })
+                .collect()
+        }
+        NodeKind::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(identifier),
+            ..
+        }) if is_filter_utility(&identifier.sym) && store.get_type_argument(&identifier.sym).is_none() => {
+            match filter_union_members(&identifier.sym, &root, file_path, store) {
+                Some(members) => members,
+                None => vec![(root, file_path.to_string())],
+            }
+        }
+        NodeKind::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(identifier),
+            ..
+        }) => match store.get_type_argument(&identifier.sym) {
+            Some((TypeArgument::Type { node, file_path }, depth)) => {
+                let scopes = store.suspend_type_arguments(depth);
+                let members = expand_union_members(node, &file_path, store);
+                store.resume_type_arguments(scopes);
+                members
+            }
+            Some((TypeArgument::Key(_), _)) => vec![(root, file_path.to_string())],
+            None => match resolve_type(root.clone(), file_path, store) {
+                Some((resolved, resolved_file_path))
+                    if matches!(
+                        resolved.kind,
+                        NodeKind::TsUnionType(_) | NodeKind::TsUnionOrIntersectionType(_)
+                    ) =>
+                {
+                    expand_union_members(resolved, &resolved_file_path, store)
+                }
+                _ => vec![(root, file_path.to_string())],
+            },
+        },
+        _ => vec![(root, file_path.to_string())],
+    }
+}
+
+// what the evaluator knows about a type when deciding whether one extends another
+#[derive(PartialEq)]
+enum TypeFact {
+    Literal(String, &'static str),
+    Keyword(TsKeywordTypeKind),
+    Named(String),
+    Unknown,
+}
+
+fn get_type_fact(root: Rc<SchemyNode<'static>>, file_path: &str, store: &mut Store) -> TypeFact {
+    match root.kind {
+        NodeKind::TsType(_) | NodeKind::TsTypeAnnotation(_) | NodeKind::TsUnionOrIntersectionType(_) => {
+            match root.children().into_iter().next() {
+                Some(child) => get_type_fact(child, file_path, store),
+                None => TypeFact::Unknown,
+            }
+        }
+        NodeKind::TsParenthesizedType(raw) => {
+            get_type_fact(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsLitType(raw) => match &raw.lit {
+            TsLit::Str(raw_str) => TypeFact::Literal(raw_str.value.to_string(), "string"),
+            TsLit::Number(raw_num) => TypeFact::Literal(format!("{}", raw_num.value), "number"),
+            TsLit::Bool(raw_bool) => TypeFact::Literal(format!("{}", raw_bool.value), "boolean"),
+            TsLit::BigInt(raw_int) => TypeFact::Literal(format!("{}", raw_int.value), "bigint"),
+            TsLit::Tpl(_) => TypeFact::Unknown,
+        },
+        NodeKind::TsKeywordType(raw) => TypeFact::Keyword(raw.kind),
+        // a keyof with a single key is that key, wider ones are compared member by member after expansion
+        NodeKind::TsTypeOperator(raw) if raw.op == TsTypeOperatorOp::KeyOf => {
+            let mut keys = get_key_names(root.clone(), file_path, store);
+            match keys.len() {
+                1 => TypeFact::Literal(keys.remove(0), "string"),
+                _ => TypeFact::Unknown,
+            }
+        }
+        NodeKind::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(identifier),
+            ..
+        }) => match store.get_type_argument(&identifier.sym) {
+            Some((TypeArgument::Type { node, file_path }, depth)) => {
+                let scopes = store.suspend_type_arguments(depth);
+                let fact = get_type_fact(node, &file_path, store);
+                store.resume_type_arguments(scopes);
+                fact
+            }
+            Some((TypeArgument::Key(key), _)) => TypeFact::Literal(key, "string"),
+            None => match resolve_type(root.clone(), file_path, store) {
+                Some((resolved, resolved_file_path))
+                    if matches!(resolved.kind, NodeKind::TsLitType(_) | NodeKind::TsKeywordType(_)) =>
+                {
+                    get_type_fact(resolved, &resolved_file_path, store)
+                }
+                _ => TypeFact::Named(store.get_root_declaration_name(file_path, &identifier.sym)),
+            },
+        },
+        _ => TypeFact::Unknown,
+    }
+}
+
+fn is_assignable(
+    source: &Rc<SchemyNode<'static>>,
+    source_file_path: &str,
+    target: &Rc<SchemyNode<'static>>,
+    target_file_path: &str,
+    store: &mut Store,
+) -> Option<bool> {
+    let source = get_type_fact(source.clone(), source_file_path, store);
+    let mut is_assignable = Some(false);
+    for (member, member_file_path) in expand_union_members(target.clone(), target_file_path, store) {
+        match is_fact_assignable(&source, &get_type_fact(member, &member_file_path, store)) {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => is_assignable = None,
+        }
+    }
+
+    is_assignable
+}
+
+// named types are compared by name, since the evaluator does not compare structures
+fn is_fact_assignable(source: &TypeFact, target: &TypeFact) -> Option<bool> {
+    match (source, target) {
+        (TypeFact::Unknown, _) | (_, TypeFact::Unknown) => None,
+        (_, TypeFact::Keyword(TsKeywordTypeKind::TsAnyKeyword | TsKeywordTypeKind::TsUnknownKeyword)) => Some(true),
+        (TypeFact::Keyword(TsKeywordTypeKind::TsNeverKeyword), _) => Some(true),
+        (TypeFact::Keyword(TsKeywordTypeKind::TsAnyKeyword), _) => None,
+        (TypeFact::Literal(_, literal_type), TypeFact::Keyword(kind)) => {
+            Some(get_keyword_type(*kind) == Some(*literal_type))
+        }
+        (TypeFact::Named(_), TypeFact::Keyword(kind)) => Some(*kind == TsKeywordTypeKind::TsObjectKeyword),
+        (source, target) => Some(source == target),
+    }
+}
+
+fn get_keyword_type(kind: TsKeywordTypeKind) -> Option<&'static str> {
+    match kind {
+        TsKeywordTypeKind::TsStringKeyword => Some("string"),
+        TsKeywordTypeKind::TsNumberKeyword => Some("number"),
+        TsKeywordTypeKind::TsBooleanKeyword => Some("boolean"),
+        TsKeywordTypeKind::TsBigIntKeyword => Some("bigint"),
+        _ => None,
+    }
+}
+
+// unions of literals expand into every combination up to this many values, after which a pattern is used
+const MAX_TEMPLATE_VALUES: usize = 100;
+
+enum TemplateSegment {
+    Literals(Vec<String>),
+    Pattern(String),
+}
+
+impl TemplateSegment {
+    fn to_pattern(&self) -> String {
+        match self {
+            TemplateSegment::Literals(values) if values.len() == 1 => escape_pattern(&values[0]),
+            TemplateSegment::Literals(values) => {
+                let values: Vec<String> = values.iter().map(|value| escape_pattern(value)).collect();
+                format!("(?:{})", values.join("|"))
+            }
+            TemplateSegment::Pattern(pattern) => pattern.clone(),
+        }
+    }
+}
+
+fn get_template_segments(
+    root: &Rc<SchemyNode<'static>>,
+    template: &'static TsTplLitType,
+    file_path: &str,
+    store: &mut Store,
+) -> Vec<TemplateSegment> {
+    let mut segments = vec![];
+    for (index, quasi) in template.quasis.iter().enumerate() {
+        let text = quasi.cooked.as_ref().unwrap_or(&quasi.raw);
+        segments.push(TemplateSegment::Literals(vec![text.to_string()]));
+
+        if let Some(placeholder) = template.types.get(index) {
+            let placeholder = root.to_child(NodeKind::TsType(placeholder));
+            segments.push(get_placeholder_segment(placeholder, file_path, store));
+        }
+    }
+
+    segments
+}
+
+fn get_placeholder_segment(root: Rc<SchemyNode<'static>>, file_path: &str, store: &mut Store) -> TemplateSegment {
+    match root.kind {
+        NodeKind::TsType(_) | NodeKind::TsTypeAnnotation(_) | NodeKind::TsUnionOrIntersectionType(_) => {
+            match root.children().into_iter().next() {
+                Some(child) => get_placeholder_segment(child, file_path, store),
+                None => TemplateSegment::Pattern(String::from(".*")),
+            }
+        }
+        NodeKind::TsParenthesizedType(raw) => {
+            get_placeholder_segment(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsLitType(raw) => match &raw.lit {
+            TsLit::Str(raw_str) => TemplateSegment::Literals(vec![raw_str.value.to_string()]),
+            TsLit::Number(raw_num) => TemplateSegment::Literals(vec![format!("{}", raw_num.value)]),
+            TsLit::Bool(raw_bool) => TemplateSegment::Literals(vec![format!("{}", raw_bool.value)]),
+            TsLit::BigInt(raw_int) => TemplateSegment::Literals(vec![format!("{}", raw_int.value)]),
+            TsLit::Tpl(raw_tpl) => join_template_segments(get_template_segments(&root, raw_tpl, file_path, store)),
+        },
+        NodeKind::TsKeywordType(raw) => match raw.kind {
+            TsKeywordTypeKind::TsNumberKeyword => TemplateSegment::Pattern(String::from(r"-?\d+(?:\.\d+)?")),
+            TsKeywordTypeKind::TsBigIntKeyword => TemplateSegment::Pattern(String::from(r"-?\d+")),
+            TsKeywordTypeKind::TsBooleanKeyword => {
+                TemplateSegment::Literals(vec![String::from("true"), String::from("false")])
+            }
+            TsKeywordTypeKind::TsNullKeyword => TemplateSegment::Literals(vec![String::from("null")]),
+            TsKeywordTypeKind::TsUndefinedKeyword => TemplateSegment::Literals(vec![String::from("undefined")]),
+            _ => TemplateSegment::Pattern(String::from(".*")),
+        },
+        NodeKind::TsUnionType(_) => {
+            let segments: Vec<TemplateSegment> = root
+                .children()
+                .into_iter()
+                .map(|child| get_placeholder_segment(child, file_path, store))
+                .collect();
+
+            let mut values = vec![];
+            for segment in &segments {
+                match segment {
+                    TemplateSegment::Literals(literals) => {
+                        for literal in literals {
+                            if !values.contains(literal) {
+                                values.push(literal.clone());
+                            }
+                        }
+                    }
+                    TemplateSegment::Pattern(_) => {
+                        let patterns: Vec<String> = segments.iter().map(|segment| segment.to_pattern()).collect();
+                        return TemplateSegment::Pattern(format!("(?:{})", patterns.join("|")));
+                    }
+                }
+            }
+
+            TemplateSegment::Literals(values)
+        }
+        NodeKind::TsEnumDecl(raw) => {
+            let mut values = vec![];
+            for member in &raw.members {
+                match member.init.as_deref() {
+                    Some(Expr::Lit(Lit::Str(raw_str))) => values.push(raw_str.value.to_string()),
+                    Some(Expr::Lit(Lit::Num(raw_num))) => values.push(format!("{}", raw_num.value)),
+                    _ => return TemplateSegment::Pattern(String::from(".*")),
+                }
+            }
+
+            TemplateSegment::Literals(values)
+        }
+        NodeKind::TsTypeRef(raw) if begin_type_evaluation(&root, raw.span, file_path, store) => {
+            let segment = match resolve_type(root.clone(), file_path, store) {
+                Some((resolved, file_path)) => get_placeholder_segment(resolved, &file_path, store),
+                None => TemplateSegment::Pattern(String::from(".*")),
+            };
+
+            store.end_evaluation();
+            segment
+        }
+        _ => TemplateSegment::Pattern(String::from(".*")),
+    }
+}
+
+fn join_template_segments(segments: Vec<TemplateSegment>) -> TemplateSegment {
+    let mut values = vec![String::new()];
+    for segment in &segments {
+        match segment {
+            TemplateSegment::Literals(literals) if values.len() * literals.len() <= MAX_TEMPLATE_VALUES => {
+                values = values
+                    .iter()
+                    .flat_map(|value| literals.iter().map(move |literal| format!("{}{}", value, literal)))
+                    .collect();
+            }
+            _ => {
+                let patterns: Vec<String> = segments.iter().map(|segment| segment.to_pattern()).collect();
+                return TemplateSegment::Pattern(patterns.concat());
+            }
+        }
+    }
+
+    TemplateSegment::Literals(values)
+}
+
+fn escape_pattern(text: &str) -> String {
+    let mut pattern = String::new();
+    for character in text.chars() {
+        if "\\.^$|?*+()[]{}/".contains(character) {
+            pattern.push('\\');
+        }
+        pattern.push(character);
+    }
+
+    pattern
+}
+
+fn get_prop_name(key: &PropName) -> Option<String> {
+    match key {
+        PropName::Ident(raw) => Some(raw.sym.to_string()),
+        PropName::Str(raw) => Some(raw.value.to_string()),
+        PropName::Num(raw) => Some(format!("{}", raw.value)),
+        _ => None,
+    }
+}
+
+// a call that is reached again while its own return type is being followed, like `const f = () => f()`,
+// has no type that can be written down
+fn begin_call_evaluation(root: &Rc<SchemyNode<'static>>, call: &CallExpr, file_path: &str, store: &mut Store) -> bool {
+    if store.begin_evaluation(call.span) {
+        return true;
+    }
+
+    let message = "Could not evaluate a call whose return type refers back to itself";
+    store.add_diagnostic(Severity::Warning, message, file_path, Some(root));
+    false
+}
+
+// a type that is reached again while it is being evaluated, like `type T = T[number]`, has no schema
+fn begin_type_evaluation(root: &Rc<SchemyNode<'static>>, span: Span, file_path: &str, store: &mut Store) -> bool {
+    if store.begin_evaluation(span) {
+        return true;
+    }
+
+    let message = "Could not evaluate a type that refers back to itself";
+    store.add_diagnostic(Severity::Warning, message, file_path, Some(root));
+    false
+}
+
+fn find_return_type(
+    root: &Rc<SchemyNode<'static>>,
+    call: &'static CallExpr,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    let callee = match &call.callee {
+        Callee::Expr(callee) => root.to_child(NodeKind::Expr(callee)),
+        _ => return None,
+    };
+
+    let (function, file_path) = resolve_type(callee, file_path, store)?;
+    get_return_type(&function, &file_path)
+}
+
+fn get_return_type(function: &Rc<SchemyNode<'static>>, file_path: &str) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    match function.return_type() {
+        Some(return_type) => unwrap_return_type(return_type).map(|return_type| (return_type, file_path.to_string())),
+        None => match function.kind {
+            NodeKind::ArrowExpr(raw) => match &*raw.body {
+                BlockStmtOrExpr::Expr(expr) => Some((function.to_child(NodeKind::Expr(expr)), file_path.to_string())),
+                _ => None,
+            },
+            _ => None,
+        },
+    }
+}
+
+// how a parameter or tuple element is passed, which decides where it lands in the tuple
+#[derive(PartialEq)]
+enum ParamKind {
+    Required,
+    Optional,
+    Rest,
+}
+
+impl ParamKind {
+    fn from_optional(optional: bool) -> Self {
+        match optional {
+            true => ParamKind::Optional,
+            false => ParamKind::Required,
+        }
+    }
+}
+
+fn get_params(function: &Rc<SchemyNode<'static>>) -> Vec<(Option<Rc<SchemyNode<'static>>>, ParamKind)> {
+    let params: Vec<(Option<&'static TsTypeAnn>, ParamKind)> = match function.kind {
+        NodeKind::FnDecl(raw) => raw
+            .function
+            .params
+            .iter()
+            .filter_map(|param| get_pat_param(&param.pat))
+            .collect(),
+        NodeKind::FnExpr(raw) => raw
+            .function
+            .params
+            .iter()
+            .filter_map(|param| get_pat_param(&param.pat))
+            .collect(),
+        NodeKind::Method(raw) => raw
+            .function
+            .params
+            .iter()
+            .filter_map(|param| get_pat_param(&param.pat))
+            .collect(),
+        NodeKind::ArrowExpr(raw) => raw.params.iter().filter_map(get_pat_param).collect(),
+        NodeKind::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(raw)) => raw
+            .params
+            .iter()
+            .filter_map(|param| match param {
+                TsFnParam::Ident(raw) if raw.sym.eq("this") => None,
+                TsFnParam::Ident(raw) => Some((raw.type_ann.as_deref(), ParamKind::from_optional(raw.optional))),
+                TsFnParam::Array(raw) => Some((raw.type_ann.as_deref(), ParamKind::from_optional(raw.optional))),
+                TsFnParam::Rest(raw) => Some((raw.type_ann.as_deref(), ParamKind::Rest)),
+                TsFnParam::Object(raw) => Some((raw.type_ann.as_deref(), ParamKind::from_optional(raw.optional))),
+            })
+            .collect(),
+        _ => vec![],
+    };
+
+    params
+        .into_iter()
+        .map(|(annotation, kind)| {
+            let annotation = annotation.map(|annotation| function.to_child(NodeKind::TsTypeAnnotation(annotation)));
+            (annotation, kind)
+        })
+        .collect()
+}
+
+fn get_tuple_elements(
+    root: &Rc<SchemyNode<'static>>,
+    tuple: &'static TsTupleType,
+) -> Vec<(Option<Rc<SchemyNode<'static>>>, ParamKind)> {
+    tuple
+        .elem_types
+        .iter()
+        .map(|element| {
+            let (ty, kind) = match &*element.ty {
+                TsType::TsOptionalType(raw) => (&*raw.type_ann, ParamKind::Optional),
+                TsType::TsRestType(raw) => (&*raw.type_ann, ParamKind::Rest),
+                ty => (ty, ParamKind::Required),
+            };
+            (Some(root.to_child(NodeKind::TsType(ty))), kind)
+        })
+        .collect()
+}
+
+fn get_pat_param(pat: &'static Pat) -> Option<(Option<&'static TsTypeAnn>, ParamKind)> {
+    match pat {
+        Pat::Ident(raw) if raw.sym.eq("this") => None,
+        Pat::Ident(raw) => Some((raw.type_ann.as_deref(), ParamKind::from_optional(raw.optional))),
+        Pat::Array(raw) => Some((raw.type_ann.as_deref(), ParamKind::from_optional(raw.optional))),
+        Pat::Rest(raw) => Some((raw.type_ann.as_deref(), ParamKind::Rest)),
+        Pat::Object(raw) => Some((raw.type_ann.as_deref(), ParamKind::from_optional(raw.optional))),
+        Pat::Assign(raw) => get_pat_param(&raw.left).map(|(annotation, _)| (annotation, ParamKind::Optional)),
+        _ => None,
+    }
+}
+
+fn is_function(root: &Rc<SchemyNode<'static>>) -> bool {
+    matches!(
+        root.kind,
+        NodeKind::FnDecl(_)
+            | NodeKind::FnExpr(_)
+            | NodeKind::ArrowExpr(_)
+            | NodeKind::Method(_)
+            | NodeKind::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(_))
+    )
+}
+
+fn find_member_type(
+    root: &Rc<SchemyNode<'static>>,
+    member: &'static MemberExpr,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    let name = match &member.prop {
+        MemberProp::Ident(raw) => raw.sym.to_string(),
+        MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+            Expr::Lit(Lit::Str(raw)) => raw.value.to_string(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let object = root.to_child(NodeKind::Expr(&member.obj));
+    let (object, file_path) = resolve_type(object, file_path, store)?;
+    find_member(object, &name, &file_path, store)
+}
+
+fn find_member(
+    root: Rc<SchemyNode<'static>>,
+    name: &str,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    match root.kind {
+        NodeKind::ObjectLit(raw) => {
+            for prop in &raw.props {
+                match prop {
+                    PropOrSpread::Prop(raw_prop) => match &**raw_prop {
+                        Prop::KeyValue(raw) if get_prop_name(&raw.key).as_deref() == Some(name) => {
+                            return Some((root.to_child(NodeKind::Expr(&raw.value)), file_path.to_string()));
+                        }
+                        Prop::Shorthand(raw) if raw.sym.eq(name) => {
+                            return Some((root.to_child(NodeKind::Ident(raw)), file_path.to_string()));
+                        }
+                        _ => {}
+                    },
+                    PropOrSpread::Spread(raw_spread) => {
+                        let spread = root.to_child(NodeKind::Expr(&raw_spread.expr));
+                        if let Some((spread, spread_file_path)) = resolve_type(spread, file_path, store) {
+                            if let Some(member) = find_member(spread, name, &spread_file_path, store) {
+                                return Some(member);
+                            }
+                        }
+                    }
+                }
+            }
+
+            None
+        }
+        NodeKind::TsConstAssertionExpr(raw) => {
+            let value = get_asserted_value(&root, raw)?;
+            find_member(value, name, file_path, store)
+        }
+        NodeKind::TsTypeLit(_) => find_property_signature(root.members(), name, file_path),
+        NodeKind::TsInterfaceDecl(_) => {
+            if let Some(interface_body) = root.interface_body() {
+                let member = find_property_signature(interface_body.members(), name, file_path);
+                if member.is_some() {
+                    return member;
+                }
+            }
+
+            for extend in root.extends() {
+                if let Some((parent, parent_file_path)) = resolve_type(extend, file_path, store) {
+                    if let Some(member) = find_member(parent, name, &parent_file_path, store) {
+                        return Some(member);
+                    }
+                }
+            }
+
+            None
+        }
+        NodeKind::Class(_) | NodeKind::ClassDecl(_) | NodeKind::ClassExpr(_) => {
+            let class_node = match root.kind {
+                NodeKind::Class(_) => root.clone(),
+                _ => root.class()?,
+            };
+
+            let member = find_class_prop(class_node.class_props(), name, file_path)
+                .or_else(|| find_class_method(&class_node, name, file_path));
+            if member.is_some() {
+                return member;
+            }
+
+            let (base_class, base_file_path) = resolve_base_class(&root, file_path, store)?;
+            find_member(base_class, name, &base_file_path, store)
+        }
+        _ => None,
+    }
+}
+
+// the class a class declaration or expression extends
+fn resolve_base_class(
+    root: &Rc<SchemyNode<'static>>,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    let class_node = match root.kind {
+        NodeKind::Class(_) => root.clone(),
+        _ => root.class()?,
+    };
+
+    match class_node.kind {
+        NodeKind::Class(raw) => {
+            let super_class = raw.super_class.as_deref()?;
+            resolve_type(class_node.to_child(NodeKind::Expr(super_class)), file_path, store)
+        }
+        _ => None,
+    }
+}
+
+fn find_property_signature(
+    members: Vec<Rc<SchemyNode<'static>>>,
+    name: &str,
+    file_path: &str,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    for member in members {
+        match member.kind {
+            NodeKind::TsTypeElement(TsTypeElement::TsPropertySignature(raw_prop)) => match &*raw_prop.key {
+                Expr::Ident(identifier) if identifier.sym.eq(name) => {
+                    return member.type_ann().map(|annotation| (annotation, file_path.to_string()));
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn find_class_prop(
+    props: Vec<Rc<SchemyNode<'static>>>,
+    name: &str,
+    file_path: &str,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    for property in props {
+        let is_match = match property.kind {
+            NodeKind::ClassProp(raw_prop) => match &raw_prop.key {
+                PropName::Ident(identifier) => identifier.sym.eq(name),
+                _ => false,
+            },
+            NodeKind::BindingIdent(raw_ident) => raw_ident.sym.eq(name),
+            _ => false,
+        };
+
+        if is_match {
+            return property
+                .type_ann()
+                .map(|annotation| (annotation, file_path.to_string()));
+        }
+    }
+
+    None
+}
+
+fn find_class_method(
+    class_node: &Rc<SchemyNode<'static>>,
+    name: &str,
+    file_path: &str,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    for member in class_node.members() {
+        for child in member.children() {
+            match child.kind {
+                NodeKind::Method(raw_method) if get_prop_name(&raw_method.key).as_deref() == Some(name) => {
+                    return Some((child.clone(), file_path.to_string()));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    None
+}
+
+fn resolve_type(
+    root: Rc<SchemyNode<'static>>,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    match root.kind {
+        NodeKind::Expr(_)
+        | NodeKind::ExprOrSpread(_)
+        | NodeKind::TsExprWithTypeArgs(_)
+        | NodeKind::TsType(_)
+        | NodeKind::TsTypeAnnotation(_) => {
+            let child = root.children().into_iter().next()?;
+            resolve_type(child, file_path, store)
+        }
+        NodeKind::Ident(raw) => resolve_declaration(&raw.sym, file_path, true, store),
+        NodeKind::TsTypeRef(raw) => match &raw.type_name {
+            TsEntityName::Ident(identifier) if store.get_type_argument(&identifier.sym).is_some() => {
+                match store.get_type_argument(&identifier.sym)? {
+                    (TypeArgument::Type { node, file_path }, depth) => {
+                        let scopes = store.suspend_type_arguments(depth);
+                        let resolved = resolve_type(node, &file_path, store);
+                        store.resume_type_arguments(scopes);
+                        resolved
+                    }
+                    (TypeArgument::Key(_), _) => None,
+                }
+            }
+            TsEntityName::Ident(identifier) if identifier.sym.eq("Promise") || identifier.sym.eq("Awaited") => {
+                let type_param = root.type_params().into_iter().next()?;
+                resolve_type(type_param, file_path, store)
+            }
+            TsEntityName::Ident(identifier) if identifier.sym.eq("ReturnType") => {
+                let type_param = root.type_params().into_iter().next()?;
+                if !begin_type_evaluation(&root, raw.span, file_path, store) {
+                    return None;
+                }
+
+                let resolved = resolve_type(type_param, file_path, store)
+                    .and_then(|(function, file_path)| get_return_type(&function, &file_path))
+                    .and_then(|(return_type, file_path)| resolve_type(return_type, &file_path, store));
+
+                store.end_evaluation();
+                resolved
+            }
+            TsEntityName::Ident(identifier) if identifier.sym.eq("InstanceType") => {
+                let type_param = root.type_params().into_iter().next()?;
+                resolve_type(type_param, file_path, store)
+            }
+            TsEntityName::Ident(identifier) => resolve_declaration(&identifier.sym, file_path, false, store),
+            _ => None,
+        },
+        NodeKind::TsAsExpr(raw) => resolve_type(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store),
+        NodeKind::TsTypeAssertionExpr(raw) => {
+            resolve_type(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsSatisfiesExpr(raw) => {
+            resolve_type(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsTypeAliasDecl(raw) => {
+            if !begin_type_evaluation(&root, raw.span, file_path, store) {
+                return None;
+            }
+
+            let resolved = resolve_type(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store);
+            store.end_evaluation();
+            resolved
+        }
+        NodeKind::TsParenthesizedType(raw) => {
+            resolve_type(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsTypeOperator(raw) if raw.op != TsTypeOperatorOp::KeyOf => {
+            resolve_type(root.to_child(NodeKind::TsType(&raw.type_ann)), file_path, store)
+        }
+        NodeKind::TsTypeQuery(raw) => match &raw.expr_name {
+            TsTypeQueryExpr::TsEntityName(entity_name) => resolve_entity_name(entity_name, file_path, store),
+            _ => None,
+        },
+        NodeKind::TsIndexedAccessType(raw) => {
+            if !begin_type_evaluation(&root, raw.span, file_path, store) {
+                return None;
+            }
+
+            let (mut members, _) = find_indexed_members(&root, raw, file_path, store);
+            let resolved = match members.len() {
+                1 => {
+                    let (member, file_path) = members.remove(0);
+                    resolve_type(member, &file_path, store)
+                }
+                _ => None,
+            };
+
+            store.end_evaluation();
+            resolved
+        }
+        NodeKind::TsNonNullExpr(raw) => resolve_type(root.to_child(NodeKind::Expr(&raw.expr)), file_path, store),
+        // the assertion is kept so that indexed access can tell literal members from widened ones
+        NodeKind::TsConstAssertionExpr(_) => Some((root, file_path.to_string())),
+        NodeKind::ParenExpr(raw) => resolve_type(root.to_child(NodeKind::Expr(&raw.expr)), file_path, store),
+        NodeKind::AwaitExpr(raw) => resolve_type(root.to_child(NodeKind::Expr(&raw.arg)), file_path, store),
+        NodeKind::NewExpr(raw) => resolve_type(root.to_child(NodeKind::Expr(&raw.callee)), file_path, store),
+        NodeKind::CallExpr(raw) => {
+            if !begin_call_evaluation(&root, raw, file_path, store) {
+                return None;
+            }
+
+            let resolved = find_return_type(&root, raw, file_path, store)
+                .and_then(|(return_type, file_path)| resolve_type(return_type, &file_path, store));
+
+            store.end_evaluation();
+            resolved
+        }
+        NodeKind::MemberExpr(raw) => {
+            if !begin_type_evaluation(&root, raw.span, file_path, store) {
+                return None;
+            }
+
+            let resolved = find_member_type(&root, raw, file_path, store)
+                .and_then(|(member, file_path)| resolve_type(member, &file_path, store));
+
+            store.end_evaluation();
+            resolved
+        }
+        _ => Some((root, file_path.to_string())),
+    }
+}
+
+fn resolve_declaration(
+    type_name: &str,
+    file_path: &str,
+    is_value: bool,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    let declaration = match is_value {
+        true => store.get_root_value_declaration(file_path, type_name),
+        false => store.get_root_declaration(file_path, type_name),
+    };
+
+    match declaration {
+        Some(Declaration::Type { node }) => match node.kind {
+            NodeKind::TsTypeAliasDecl(_) => resolve_type(node, file_path, store),
+            _ => Some((node, file_path.to_string())),
+        },
+        Some(Declaration::Value { node }) => resolve_type(node, file_path, store),
+        Some(Declaration::Import { name, source_file_name }) | Some(Declaration::Export { name, source_file_name }) => {
+            store.parse_module(&source_file_name);
+            resolve_declaration(&name, &source_file_name, is_value, store)
+        }
+        _ => None,
+    }
+}
+
+fn resolve_entity_name(
+    entity_name: &'static TsEntityName,
+    file_path: &str,
+    store: &mut Store,
+) -> Option<(Rc<SchemyNode<'static>>, String)> {
+    match entity_name {
+        TsEntityName::Ident(identifier) => resolve_declaration(&identifier.sym, file_path, true, store),
+        TsEntityName::TsQualifiedName(qualified_name) => {
+            let (object, file_path) = resolve_entity_name(&qualified_name.left, file_path, store)?;
+            let (member, file_path) = find_member(object, &qualified_name.right.sym, &file_path, store)?;
+            resolve_type(member, &file_path, store)
+        }
+    }
+}
+
+fn get_path_options(options: Rc<SchemyNode>) -> PathOptions {
+    let mut path_options = PathOptions::new();
+    load_options(&mut path_options, options);
+    path_options
+}
+
+fn load_options(path_options: &mut PathOptions, root: Rc<SchemyNode>) {
+    if let NodeKind::ExprOrSpread(raw_expr) = root.kind {
+        match &*raw_expr.expr {
+            Expr::Object(raw_literal) => {
+                for prop_or_spread in &raw_literal.props {
+                    match prop_or_spread.as_prop() {
+                        Some(prop) => match prop.as_ref() {
+                            Prop::KeyValue(key_value) => match &key_value.key {
+                                PropName::Ident(i) if i.sym.eq("method") => {
+                                    path_options.method = match key_value.value.deref() {
+                                        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+                                        _ => None,
+                                    }
+                                }
+                                PropName::Ident(i) if i.sym.eq("operationId") => {
+                                    path_options.operation_id = match key_value.value.deref() {
+                                        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+                                        _ => None,
+                                    }
+                                }
+                                PropName::Ident(i) if i.sym.eq("path") => {
+                                    path_options.path = match key_value.value.deref() {
+                                        Expr::Lit(Lit::Str(s)) => Some(s.value.to_string()),
+                                        _ => None,
+                                    }
+                                }
+                                PropName::Ident(i) if i.sym.eq("tags") => {
+                                    let mut tags = vec![];
+                                    match key_value.value.deref() {
+                                        Expr::Array(literal) => {
+                                            for element in &literal.elems {
+                                                if let Some(element) = element {
+                                                    match element.expr.deref() {
+                                                        Expr::Lit(Lit::Str(s)) => tags.push(s.value.to_string()),
+                                                        _ => {}
+                                                    }
+                                                }
+                                            }
+                                        }
+                                        _ => {}
+                                    }
+
+                                    if tags.len() > 0 {
                                         path_options.tags = Some(tags);
                                     }
                                 }
@@ -1026,15 +3927,27 @@ fn load_options(path_options: &mut PathOptions, root: Rc<SchemyNode>) {
     }
 }
 
-fn get_parameter_name(root: Rc<SchemyNode>) -> String {
+fn get_parameter_name(root: Rc<SchemyNode>) -> Option<String> {
     match &root.kind {
         NodeKind::TsTypeElement(TsTypeElement::TsPropertySignature(raw)) if raw.key.is_ident() => {
             let identifier = raw.key.as_ident().unwrap();
-            identifier.sym.to_string()
+            Some(identifier.sym.to_string())
         }
         _ => match root.parent() {
             Some(parent) => get_parameter_name(parent),
-            None => panic!("Could not find parameter name"),
+            None => None,
+        },
+    }
+}
+
+fn get_parameter_description(root: Rc<SchemyNode>, file_path: &str, store: &Store) -> Option<String> {
+    match &root.kind {
+        NodeKind::TsTypeElement(TsTypeElement::TsPropertySignature(raw)) => {
+            store.get_description(file_path, raw.span.lo)
+        }
+        _ => match root.parent() {
+            Some(parent) => get_parameter_description(parent, file_path, store),
+            None => None,
         },
     }
 }
@@ -1061,7 +3974,16 @@ fn get_response_options(options: &ObjectLit) -> ResponseOptions {
                         Some(k) if k.eq("description") => response_options.description = value,
                         Some(k) if k.eq("example") => response_options.example = value,
                         Some(k) if k.eq("statusCode") => response_options.status_code = value,
-                        Some(k) if k.eq("mediaType") => response_options.media_type = value,
+                        Some(k) if k.eq("mediaType") => match &*key_value.value {
+                            Expr::Array(media_types) => {
+                                for media_type in media_types.elems.iter().flatten() {
+                                    if let Expr::Lit(Lit::Str(value)) = &*media_type.expr {
+                                        response_options.media_types.push(value.value.to_string());
+                                    }
+                                }
+                            }
+                            _ => response_options.media_types.extend(value),
+                        },
                         _ => {}
                     }
                 }
@@ -0,0 +1,309 @@
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use neon::{
+    prelude::{Context, *},
+    result::Throw,
+};
+use serde_json::{json, Map, Value};
+
+use super::{
+    factory::{append_deferred_schemas, append_type_schemas, reconcile_references},
+    format_schema, get_compiler_options,
+    schema::{OpenApi, OpenApiOptions, UnresolvedReferences},
+    state::{Diagnostic, Store},
+    write_file,
+};
+
+const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub fn generate_json_schemas(
+    schemas_result: Handle<JsObject>,
+    options_handle: Handle<JsObject>,
+    diagnostics: &mut Vec<Diagnostic>,
+    cx: &mut FunctionContext,
+) -> Result<(), Throw> {
+    let json_schema_handle = match options_handle.get_opt::<JsObject, FunctionContext, &str>(cx, "jsonSchema")? {
+        Some(json_schema_handle) => json_schema_handle,
+        None => return Ok(()),
+    };
+
+    let mut options = OpenApiOptions::new();
+    options.compiler_options = get_compiler_options(options_handle, cx)?;
+    if let Some(indent) = json_schema_handle.get_opt::<JsNumber, FunctionContext, &str>(cx, "indent")? {
+        options.indent = indent.value(cx).max(0.0) as usize;
+    }
+
+    if let Some(strategy) = json_schema_handle.get_opt::<JsString, FunctionContext, &str>(cx, "unresolvedReferences")? {
+        options.unresolved_references = match strategy.value(cx).as_str() {
+            "error" => UnresolvedReferences::Error,
+            _ => UnresolvedReferences::Empty,
+        };
+    }
+
+    let bundle = match json_schema_handle.get_opt::<JsBoolean, FunctionContext, &str>(cx, "bundle")? {
+        Some(bundle) => bundle.value(cx),
+        None => false,
+    };
+
+    let base_id = match json_schema_handle.get_opt::<JsString, FunctionContext, &str>(cx, "id")? {
+        Some(id) => id.value(cx),
+        None => String::new(),
+    };
+
+    let mut type_names = vec![];
+    if let Some(types) = json_schema_handle.get_opt::<JsArray, FunctionContext, &str>(cx, "types")? {
+        for type_name in types.to_vec(cx)? {
+            type_names.push(type_name.downcast_or_throw::<JsString, _>(cx)?.value(cx));
+        }
+    }
+
+    let mut store = Store::new(options);
+    let mut open_api = OpenApi::new();
+    let mut schema_names: Vec<String> = vec![];
+    let paths = json_schema_handle.get::<JsArray, FunctionContext, &str>(cx, "entry")?;
+    for path in paths.to_vec(cx)? {
+        let path = path.downcast_or_throw::<JsString, _>(cx)?.value(cx);
+        for schema_name in append_type_schemas(&mut open_api, &path, &type_names, &mut store) {
+            if !schema_names.contains(&schema_name) {
+                schema_names.push(schema_name);
+            }
+        }
+    }
+
+    append_deferred_schemas(&mut open_api, &mut store);
+    let unresolved = reconcile_references(&mut open_api, &mut store);
+    diagnostics.extend(store.take_diagnostics());
+    if !unresolved.is_empty() {
+        return cx.throw_error(unresolved.join("\n"));
+    }
+
+    let definitions = get_definitions(&open_api);
+    let cwd = options_handle
+        .get_opt::<JsString, FunctionContext, &str>(cx, "cwd")?
+        .map(|cwd| cwd.value(cx));
+    let output = json_schema_handle
+        .get_opt::<JsString, FunctionContext, &str>(cx, "output")?
+        .map(|output| match &cwd {
+            Some(cwd) => PathBuf::from(cwd).join(output.value(cx)),
+            None => PathBuf::from(output.value(cx)),
+        });
+
+    let mut documents = vec![];
+    if bundle {
+        let file_name = output
+            .as_ref()
+            .and_then(|output| output.file_name())
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or(String::from("schemas.json"));
+
+        let document = json!({
+            "$schema": DRAFT_2020_12,
+            "$id": format!("{base_id}{file_name}"),
+            "$defs": definitions,
+        });
+
+        documents.push((file_name, document));
+    } else {
+        for schema_name in &schema_names {
+            let file_name = format!("{schema_name}.json");
+            let document = get_document(schema_name, &definitions, &format!("{base_id}{file_name}"));
+            documents.push((file_name, document));
+        }
+    }
+
+    let schema_result: Handle<JsObject> = cx.empty_object();
+    match output {
+        Some(output) => {
+            if !bundle {
+                if let Err(error) = fs::create_dir_all(&output) {
+                    return cx.throw_error(format!("Could not create '{}': {}", output.display(), error));
+                }
+            }
+
+            let filepaths = cx.empty_array();
+            for (index, (file_name, document)) in documents.iter().enumerate() {
+                let filepath = match bundle {
+                    true => output.clone(),
+                    false => output.join(file_name),
+                };
+
+                let contents = format_schema(document, store.options(), cx)?;
+                write_file(&filepath, &contents, cx)?;
+
+                let filepath = cx.string(filepath.to_string_lossy());
+                filepaths.set(cx, index as u32, filepath)?;
+            }
+
+            schema_result.set(cx, "filepaths", filepaths)?;
+        }
+        None if bundle => {
+            if let Some((_, document)) = documents.first() {
+                let schema = format_schema(document, store.options(), cx)?;
+                let schema = cx.string(schema);
+                schema_result.set(cx, "schema", schema)?;
+            }
+        }
+        None => {
+            let schemas = cx.empty_object();
+            for (index, (_, document)) in documents.iter().enumerate() {
+                let schema = format_schema(document, store.options(), cx)?;
+                let schema = cx.string(schema);
+                schemas.set(cx, schema_names[index].as_str(), schema)?;
+            }
+
+            schema_result.set(cx, "schemas", schemas)?;
+        }
+    }
+
+    schemas_result.set(cx, "jsonSchema", schema_result)?;
+
+    Ok(())
+}
+
+fn get_definitions(open_api: &OpenApi) -> BTreeMap<String, Value> {
+    let mut definitions = BTreeMap::new();
+    let mut components = json!(open_api.components);
+    if let Value::Object(schemas) = components["schemas"].take() {
+        for (name, mut schema) in schemas {
+            remove_openapi_keywords(&mut schema);
+            replace_references(&mut schema, "#/components/schemas/", "#/$defs/");
+            definitions.insert(name, schema);
+        }
+    }
+
+    definitions
+}
+
+fn get_document(schema_name: &str, definitions: &BTreeMap<String, Value>, id: &str) -> Value {
+    let mut document = Map::new();
+    document.insert("$schema".into(), json!(DRAFT_2020_12));
+    document.insert("$id".into(), json!(id));
+
+    let mut schema = definitions.get(schema_name).cloned().unwrap_or(json!({}));
+    replace_reference(&mut schema, &format!("#/$defs/{schema_name}"), "#");
+    if let Value::Object(schema) = schema {
+        document.extend(schema);
+    }
+
+    let mut defs = BTreeMap::new();
+    let mut queue = vec![];
+    for value in document.values() {
+        find_references(value, &mut queue);
+    }
+    while let Some(name) = queue.pop() {
+        if name.eq(schema_name) || defs.contains_key(&name) {
+            continue;
+        }
+
+        if let Some(definition) = definitions.get(&name) {
+            let mut definition = definition.clone();
+            replace_reference(&mut definition, &format!("#/$defs/{schema_name}"), "#");
+            find_references(&definition, &mut queue);
+            defs.insert(name, definition);
+        }
+    }
+
+    if !defs.is_empty() {
+        document.insert("$defs".into(), json!(defs));
+    }
+
+    Value::Object(document)
+}
+
+// `discriminator` and `example` are OpenAPI vocabulary that strict JSON Schema validators reject;
+// the oneOf already describes the variants and examples is the 2020-12 spelling of example
+fn remove_openapi_keywords(value: &mut Value) -> () {
+    match value {
+        Value::Object(object) => {
+            object.remove("discriminator");
+            if let Some(example) = object.remove("example") {
+                object.entry("examples").or_insert(json!([example]));
+            }
+
+            for (key, child) in object.iter_mut() {
+                match key.as_str() {
+                    "properties" | "patternProperties" | "$defs" => {
+                        if let Some(children) = child.as_object_mut() {
+                            for (_, child) in children.iter_mut() {
+                                remove_openapi_keywords(child);
+                            }
+                        }
+                    }
+                    "examples" | "default" | "enum" | "const" => {}
+                    _ => remove_openapi_keywords(child),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                remove_openapi_keywords(item);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn find_references(value: &Value, names: &mut Vec<String>) -> () {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object {
+                match (key.as_str(), child) {
+                    ("$ref", Value::String(reference)) => {
+                        if let Some(name) = reference.strip_prefix("#/$defs/") {
+                            names.push(name.to_string());
+                        }
+                    }
+                    _ => find_references(child, names),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                find_references(item, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn replace_references(value: &mut Value, from: &str, to: &str) -> () {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object.iter_mut() {
+                match (key.as_str(), child) {
+                    ("$ref", Value::String(reference)) if reference.starts_with(from) => {
+                        *reference = reference.replacen(from, to, 1);
+                    }
+                    (_, child) => replace_references(child, from, to),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                replace_references(item, from, to);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn replace_reference(value: &mut Value, from: &str, to: &str) -> () {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object.iter_mut() {
+                match (key.as_str(), child) {
+                    ("$ref", Value::String(reference)) if reference == from => {
+                        *reference = to.to_string();
+                    }
+                    (_, child) => replace_reference(child, from, to),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                replace_reference(item, from, to);
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,27 +1,92 @@
+mod downlevel;
 mod factory;
+mod json_schema;
 mod schema;
 mod state;
+mod validation;
 
-use std::{fs::File, io::Write, path::PathBuf};
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
 
 use neon::{
     prelude::{Context, *},
     result::Throw,
 };
-use serde_json::json;
+use serde::Serialize;
+use serde_json::{json, ser::PrettyFormatter, Serializer};
+use swc_ecma_ast::EsVersion;
 
-use crate::typescript::ModuleCache;
+use crate::typescript::CompilerOptions;
 
 use self::{
-    factory::{append_deferred_schemas, append_schema},
-    schema::OpenApi,
-    state::Store,
+    downlevel::downlevel_schema,
+    factory::{append_deferred_schemas, append_schema, reconcile_references},
+    schema::{
+        OpenApi, OpenApiOptions, OpenApiVersion, OutputFormat, ParameterForm, SchemaNameCollision, UnresolvedReferences,
+    },
+    state::{Diagnostic, Store},
+    validation::validate_schema,
 };
 
-fn merge_schemas(open_api: &OpenApi, base_schema: serde_json::Value) -> Result<String, Throw> {
+pub use self::json_schema::generate_json_schemas;
+
+fn merge_schemas(open_api: &OpenApi, base_schema: serde_json::Value, version: OpenApiVersion) -> serde_json::Value {
     let mut generated = json!(open_api);
     merge(&mut generated, &base_schema);
-    Ok(generated.to_string())
+
+    if version == OpenApiVersion::V3_0 {
+        downlevel_schema(&mut generated);
+    }
+
+    generated
+}
+
+// the version asked for by openApiVersion, or else the one the base schema declares
+fn get_version(options: &OpenApiOptions, base_schema: &serde_json::Value) -> Result<OpenApiVersion, String> {
+    let base_version = base_schema.get("openapi").and_then(|version| version.as_str());
+    match (options.version, base_version) {
+        (Some(version), Some(base_version)) if version != OpenApiVersion::parse(base_version) => Err(format!(
+            "openApiVersion '{}' does not match the base schema's openapi '{}'",
+            version.as_str(),
+            base_version
+        )),
+        (Some(version), _) => Ok(version),
+        (None, Some(base_version)) => Ok(OpenApiVersion::parse(base_version)),
+        (None, None) => Ok(OpenApiVersion::V3_1),
+    }
+}
+
+fn format_schema(
+    schema: &serde_json::Value,
+    options: &OpenApiOptions,
+    cx: &mut FunctionContext,
+) -> Result<String, Throw> {
+    match serialize_schema(schema, options) {
+        Ok(contents) => Ok(contents),
+        Err(error) => cx.throw_error(error),
+    }
+}
+
+fn serialize_schema(schema: &serde_json::Value, options: &OpenApiOptions) -> Result<String, String> {
+    match options.format {
+        OutputFormat::Yaml => {
+            serde_yaml::to_string(schema).map_err(|error| format!("Could not serialize schema to yaml: {}", error))
+        }
+        OutputFormat::Json if options.indent > 0 => {
+            let indent = " ".repeat(options.indent);
+            let mut buffer = vec![];
+            let mut serializer =
+                Serializer::with_formatter(&mut buffer, PrettyFormatter::with_indent(indent.as_bytes()));
+            schema
+                .serialize(&mut serializer)
+                .map_err(|error| format!("Could not serialize schema to json: {}", error))?;
+            String::from_utf8(buffer).map_err(|error| format!("Could not serialize schema to json: {}", error))
+        }
+        OutputFormat::Json => Ok(schema.to_string()),
+    }
 }
 
 fn merge(target: &mut serde_json::Value, overlay: &serde_json::Value) {
@@ -41,47 +106,211 @@ fn merge(target: &mut serde_json::Value, overlay: &serde_json::Value) {
     }
 }
 
-fn generate_schema(open_api_handle: Handle<JsObject>, cx: &mut FunctionContext) -> Result<String, Throw> {
-    let mut store = Store::new();
-    let mut module_cache = ModuleCache::new();
+fn get_open_api_options(open_api_handle: Handle<JsObject>, cx: &mut FunctionContext) -> Result<OpenApiOptions, Throw> {
+    let mut options = OpenApiOptions::new();
+
+    if let Some(form) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "parameterForm")? {
+        options.parameter_form = match form.value(cx).as_str() {
+            "content" => ParameterForm::Content,
+            _ => ParameterForm::Schema,
+        };
+    }
+
+    if let Some(enabled) = open_api_handle.get_opt::<JsBoolean, FunctionContext, &str>(cx, "inferResponses")? {
+        options.infer_responses = enabled.value(cx);
+    }
+
+    if let Some(enabled) = open_api_handle.get_opt::<JsBoolean, FunctionContext, &str>(cx, "returnTypeResponses")? {
+        options.return_type_responses = enabled.value(cx);
+    }
+
+    if let Some(description) =
+        open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "returnTypeDescription")?
+    {
+        options.return_type_description = description.value(cx);
+    }
+
+    if let Some(strategy) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "schemaNameCollision")? {
+        options.schema_name_collision = match strategy.value(cx).as_str() {
+            "error" => SchemaNameCollision::Error,
+            "prefix" => SchemaNameCollision::Prefix,
+            _ => SchemaNameCollision::Suffix,
+        };
+    }
+
+    if let Some(enabled) = open_api_handle.get_opt::<JsBoolean, FunctionContext, &str>(cx, "hoistSchemas")? {
+        options.hoist_schemas = enabled.value(cx);
+    }
+
+    if let Some(template) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "responseSchemaName")? {
+        options.response_schema_name = template.value(cx);
+    }
+
+    if let Some(template) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "bodySchemaName")? {
+        options.body_schema_name = template.value(cx);
+    }
+
+    if let Some(version) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "openApiVersion")? {
+        options.version = Some(OpenApiVersion::parse(&version.value(cx)));
+    }
+
+    if let Some(output) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "output")? {
+        let output = output.value(cx);
+        match Path::new(&output).extension().and_then(|extension| extension.to_str()) {
+            Some("yaml") | Some("yml") => options.format = OutputFormat::Yaml,
+            _ => {}
+        }
+    }
+
+    if let Some(format) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "format")? {
+        options.format = match format.value(cx).as_str() {
+            "json" => OutputFormat::Json,
+            "yaml" | "yml" => OutputFormat::Yaml,
+            format => return cx.throw_error(format!("Unknown format '{}', expected 'json' or 'yaml'", format)),
+        };
+    }
+
+    if let Some(indent) = open_api_handle.get_opt::<JsNumber, FunctionContext, &str>(cx, "indent")? {
+        options.indent = indent.value(cx).max(0.0) as usize;
+    }
+
+    if let Some(strategy) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "unresolvedReferences")? {
+        options.unresolved_references = match strategy.value(cx).as_str() {
+            "error" => UnresolvedReferences::Error,
+            _ => UnresolvedReferences::Empty,
+        };
+    }
+
+    if let Some(enabled) = open_api_handle.get_opt::<JsBoolean, FunctionContext, &str>(cx, "failOnInvalid")? {
+        options.fail_on_invalid = enabled.value(cx);
+    }
+
+    Ok(options)
+}
+
+fn get_compiler_options(options_handle: Handle<JsObject>, cx: &mut FunctionContext) -> Result<CompilerOptions, Throw> {
+    let mut compiler_options = CompilerOptions::default();
+    let compiler_options_handle =
+        match options_handle.get_opt::<JsObject, FunctionContext, &str>(cx, "compilerOptions")? {
+            Some(compiler_options_handle) => compiler_options_handle,
+            None => return Ok(compiler_options),
+        };
+
+    if let Some(jsx) = compiler_options_handle.get_opt::<JsString, FunctionContext, &str>(cx, "jsx")? {
+        compiler_options.jsx = !jsx.value(cx).eq_ignore_ascii_case("none");
+    }
+
+    if let Some(target) = compiler_options_handle.get_opt::<JsString, FunctionContext, &str>(cx, "target")? {
+        compiler_options.target = match target.value(cx).to_lowercase().as_str() {
+            "es3" => EsVersion::Es3,
+            "es5" => EsVersion::Es5,
+            "es6" | "es2015" => EsVersion::Es2015,
+            "es2016" => EsVersion::Es2016,
+            "es2017" => EsVersion::Es2017,
+            "es2018" => EsVersion::Es2018,
+            "es2019" => EsVersion::Es2019,
+            "es2020" => EsVersion::Es2020,
+            "es2021" => EsVersion::Es2021,
+            "es2022" => EsVersion::Es2022,
+            // targets newer than the parser knows about are parsed with everything it supports
+            _ => EsVersion::EsNext,
+        };
+    }
+
+    Ok(compiler_options)
+}
+
+fn generate_schema(
+    open_api_handle: Handle<JsObject>,
+    compiler_options: CompilerOptions,
+    diagnostics: &mut Vec<Diagnostic>,
+    cx: &mut FunctionContext,
+) -> Result<(String, Vec<String>), Throw> {
+    let mut options = get_open_api_options(open_api_handle, cx)?;
+    options.compiler_options = compiler_options;
+    let mut store = Store::new(options);
     let paths = open_api_handle.get::<JsArray, FunctionContext, &str>(cx, "entry")?;
 
     let mut open_api = OpenApi::new();
     for path in paths.to_vec(cx)? {
         let path = path.downcast_or_throw::<JsString, _>(cx)?.value(cx);
-        append_schema(&mut open_api, &path, &mut module_cache, &mut store);
+        append_schema(&mut open_api, &path, &mut store);
     }
 
-    append_deferred_schemas(&mut open_api, &mut module_cache, &mut store);
+    append_deferred_schemas(&mut open_api, &mut store);
+
+    let collisions = store.schema_name_collisions();
+    if !collisions.is_empty() {
+        return cx.throw_error(collisions.join("\n"));
+    }
+
+    let unresolved = reconcile_references(&mut open_api, &mut store);
+    diagnostics.extend(store.take_diagnostics());
+    if !unresolved.is_empty() {
+        return cx.throw_error(unresolved.join("\n"));
+    }
+
+    if store.options().hoist_schemas {
+        open_api.hoist_schemas(store.options());
+    }
 
     let base_handle: Handle<JsString> = open_api_handle.get(cx, "base")?;
-    let base = serde_json::from_str(&base_handle.value(cx)).expect("Could not deserialize base schema");
+    let base = match serde_json::from_str(&base_handle.value(cx)) {
+        Ok(base) => base,
+        Err(error) => return cx.throw_error(format!("Could not deserialize base schema: {}", error)),
+    };
+
+    let version = match get_version(store.options(), &base) {
+        Ok(version) => version,
+        Err(error) => return cx.throw_error(error),
+    };
 
-    merge_schemas(&mut open_api, base)
+    let schema = merge_schemas(&open_api, base, version);
+    let errors = validate_schema(&schema, version == OpenApiVersion::V3_1);
+    if !errors.is_empty() && store.options().fail_on_invalid {
+        return cx.throw_error(errors.join("\n"));
+    }
+
+    let schema = format_schema(&schema, store.options(), cx)?;
+    Ok((schema, errors))
 }
 
-pub fn generate_openapi_debug(paths: Vec<String>) -> Result<String, Throw> {
-    let mut store = Store::new();
-    let mut module_cache = ModuleCache::new();
+pub fn generate_openapi_debug(paths: Vec<String>) -> Result<String, String> {
+    let mut store = Store::new(OpenApiOptions::new());
 
     let mut open_api = OpenApi::new();
     for path in paths {
-        append_schema(&mut open_api, &path, &mut module_cache, &mut store);
+        append_schema(&mut open_api, &path, &mut store);
     }
 
-    append_deferred_schemas(&mut open_api, &mut module_cache, &mut store);
+    append_deferred_schemas(&mut open_api, &mut store);
+    reconcile_references(&mut open_api, &mut store);
 
-    merge_schemas(&mut open_api, json!({}))
+    if store.options().hoist_schemas {
+        open_api.hoist_schemas(store.options());
+    }
+
+    let schema = merge_schemas(&open_api, json!({}), OpenApiVersion::V3_1);
+    serialize_schema(&schema, store.options())
 }
 
 pub fn generate_openapi(
     schemas_result: Handle<JsObject>,
     options_handle: Handle<JsObject>,
+    diagnostics: &mut Vec<Diagnostic>,
     cx: &mut FunctionContext,
 ) -> Result<(), Throw> {
     let schema_result: Handle<JsObject> = cx.empty_object();
     if let Some(open_api_handle) = options_handle.get_opt(cx, "openApi")? as Option<Handle<JsObject>> {
-        let schema: String = generate_schema(open_api_handle, cx)?;
+        let compiler_options = get_compiler_options(options_handle, cx)?;
+        let (schema, errors) = generate_schema(open_api_handle, compiler_options, diagnostics, cx)?;
+
+        let error_handles = cx.empty_array();
+        for (index, error) in errors.iter().enumerate() {
+            let error = cx.string(error);
+            error_handles.set(cx, index as u32, error)?;
+        }
+        schema_result.set(cx, "errors", error_handles)?;
 
         if let Some(output_handle) = open_api_handle.get_opt::<JsString, FunctionContext, &str>(cx, "output")? {
             let filepath = match options_handle.get_opt::<JsString, FunctionContext, &str>(cx, "cwd")? {
@@ -93,10 +322,9 @@ pub fn generate_openapi(
                 None => PathBuf::from(output_handle.value(cx)),
             };
 
-            let mut file: File = File::create(filepath.clone()).expect("Could not create filepath: ");
-            file.write_all(schema.as_bytes()).expect("Could not write to file");
+            write_file(&filepath, &schema, cx)?;
 
-            let filepath = cx.string(filepath.to_str().unwrap());
+            let filepath = cx.string(filepath.to_string_lossy());
             schema_result.set(cx, "filepath", filepath)?;
         } else {
             let schema = cx.string(schema);
@@ -108,3 +336,49 @@ pub fn generate_openapi(
 
     Ok(())
 }
+
+pub fn set_diagnostics(
+    schemas_result: Handle<JsObject>,
+    diagnostics: &Vec<Diagnostic>,
+    cx: &mut FunctionContext,
+) -> Result<(), Throw> {
+    let diagnostic_handles = cx.empty_array();
+    for (index, diagnostic) in diagnostics.iter().enumerate() {
+        let diagnostic_handle = cx.empty_object();
+
+        let severity = cx.string(diagnostic.severity.as_str());
+        diagnostic_handle.set(cx, "severity", severity)?;
+
+        let message = cx.string(&diagnostic.message);
+        diagnostic_handle.set(cx, "message", message)?;
+
+        if let Some(file_path) = &diagnostic.file_path {
+            let file_path = cx.string(file_path);
+            diagnostic_handle.set(cx, "file", file_path)?;
+        }
+
+        if let Some(line) = diagnostic.line {
+            let line = cx.number(line as f64);
+            diagnostic_handle.set(cx, "line", line)?;
+        }
+
+        if let Some(column) = diagnostic.column {
+            let column = cx.number(column as f64);
+            diagnostic_handle.set(cx, "column", column)?;
+        }
+
+        diagnostic_handles.set(cx, index as u32, diagnostic_handle)?;
+    }
+
+    schemas_result.set(cx, "diagnostics", diagnostic_handles)?;
+
+    Ok(())
+}
+
+fn write_file(filepath: &Path, contents: &str, cx: &mut FunctionContext) -> Result<(), Throw> {
+    let result = File::create(filepath).and_then(|mut file| file.write_all(contents.as_bytes()));
+    match result {
+        Ok(_) => Ok(()),
+        Err(error) => cx.throw_error(format!("Could not write to '{}': {}", filepath.display(), error)),
+    }
+}
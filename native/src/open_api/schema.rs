@@ -1,14 +1,22 @@
-use std::{cell::RefCell, rc::Rc, vec};
-
-use ahash::{HashMap, HashMapExt, HashSet, HashSetExt};
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+    vec,
+};
+
+use indexmap::IndexMap;
 use serde::{ser::SerializeStruct, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::typescript::CompilerOptions;
 
 #[derive(Serialize, Debug)]
 pub struct OpenApi {
     #[serde(rename = "openapi")]
     pub open_api: String,
     pub components: ApiComponents,
-    pub paths: HashMap<String, ApiPath>,
+    pub paths: IndexMap<String, ApiPath>,
 }
 
 impl OpenApi {
@@ -16,34 +24,159 @@ impl OpenApi {
         OpenApi {
             open_api: "3.1.0".to_string(),
             components: ApiComponents::new(),
-            paths: HashMap::new(),
+            paths: IndexMap::new(),
         }
     }
 
     pub fn path(&mut self, key: &str) -> &mut ApiPath {
         self.paths.entry(key.to_string()).or_insert(ApiPath::new())
     }
+
+    pub(crate) fn hoist_schemas(&mut self, options: &OpenApiOptions) -> () {
+        let mut hoisted = vec![];
+        let mut paths: Vec<_> = self.paths.iter().collect();
+        paths.sort_by(|a, b| a.0.cmp(b.0));
+
+        for (path, path_item) in paths {
+            for (method, operation) in path_item.operations() {
+                let mut operation = (**operation).borrow_mut();
+                let operation_id = match &operation.operation_id {
+                    Some(operation_id) => operation_id.clone(),
+                    None => get_operation_id(method, path),
+                };
+
+                if let Some(content) = operation.body_parameter.as_mut().and_then(|b| b.content.as_mut()) {
+                    let name = get_schema_name(&options.body_schema_name, &operation_id, "");
+                    let mut content: Vec<_> = content.iter_mut().collect();
+                    content.sort_by(|a, b| a.0.cmp(b.0));
+                    for (_, content) in content {
+                        self.components.hoist_content(content, &name, &mut hoisted);
+                    }
+                }
+
+                let mut responses: Vec<_> = operation.responses.iter_mut().collect();
+                responses.sort_by(|a, b| a.0.cmp(b.0));
+                for (status_code, response) in responses {
+                    let name = get_schema_name(&options.response_schema_name, &operation_id, status_code);
+                    if let Some(content) = response.content.as_mut() {
+                        let mut content: Vec<_> = content.iter_mut().collect();
+                        content.sort_by(|a, b| a.0.cmp(b.0));
+                        for (_, content) in content {
+                            self.components.hoist_content(content, &name, &mut hoisted);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn get_operation_id(method: &str, path: &str) -> String {
+    let mut operation_id = method.to_lowercase();
+    for segment in path.split(|c: char| !c.is_alphanumeric()) {
+        let mut chars = segment.chars();
+        if let Some(first) = chars.next() {
+            operation_id.push(first.to_ascii_uppercase());
+            operation_id.extend(chars);
+        }
+    }
+    operation_id
+}
+
+fn get_schema_name(template: &str, operation_id: &str, status_code: &str) -> String {
+    template
+        .replace("{OperationId}", &capitalize(operation_id))
+        .replace("{operationId}", operation_id)
+        .replace("{Status}", status_code)
+}
+
+fn capitalize(value: &str) -> String {
+    let mut chars = value.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
 }
 
 #[derive(Serialize, Debug)]
 pub struct ApiComponents {
-    schemas: HashMap<String, ApiSchema>,
+    schemas: BTreeMap<String, ApiSchema>,
 }
 
 impl ApiComponents {
     pub fn new() -> Self {
         ApiComponents {
-            schemas: HashMap::new(),
+            schemas: BTreeMap::new(),
         }
     }
 
-    pub fn schema_with_id(&mut self, name: &str) -> &mut ApiSchema {
-        self.schemas.entry(name.to_string()).or_insert(ApiSchema::with_id(name))
+    pub fn schema(&mut self, name: &str) -> &mut ApiSchema {
+        self.schemas.entry(name.to_string()).or_insert(ApiSchema::new())
     }
 
     pub(crate) fn contains_schema(&self, type_name: &str) -> bool {
         self.schemas.contains_key(type_name)
     }
+
+    fn hoist_content(&mut self, content: &mut ApiContent, name: &str, hoisted: &mut Vec<(serde_json::Value, String)>) {
+        if let Some(schema) = content.schema.as_mut() {
+            self.hoist_schema(schema, name, hoisted);
+        }
+    }
+
+    // nested objects are hoisted before their parent and named after the path that leads to them, e.g.
+    // `{name}{Property}` and `{name}Item`; schemas already in components are left untouched
+    fn hoist_schema(&mut self, schema: &mut ApiSchema, name: &str, hoisted: &mut Vec<(serde_json::Value, String)>) {
+        if let Some(variants) = schema.one_of.as_mut() {
+            for variant in variants {
+                self.hoist_schema(variant, name, hoisted);
+            }
+        }
+
+        if let Some(items) = schema.items.as_mut() {
+            self.hoist_schema(items, &format!("{name}Item"), hoisted);
+        }
+
+        if let Some(prefix_items) = schema.prefix_items.as_mut() {
+            for (index, item) in prefix_items.iter_mut().enumerate() {
+                self.hoist_schema(item, &format!("{name}Item{}", index + 1), hoisted);
+            }
+        }
+
+        if let Some(properties) = schema.properties.as_mut() {
+            for (property_name, property) in properties.iter_mut() {
+                self.hoist_schema(property, &format!("{name}{}", capitalize(property_name)), hoisted);
+            }
+        }
+
+        if !schema.is_anonymous_object() {
+            return;
+        }
+
+        let value = match serde_json::to_value(&*schema) {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        let name = match hoisted.iter().find(|(existing, _)| existing.eq(&value)) {
+            Some((_, existing_name)) => existing_name.clone(),
+            None => {
+                let mut unique_name = name.to_string();
+                let mut index = 1;
+                while self.schemas.contains_key(&unique_name) {
+                    index += 1;
+                    unique_name = format!("{name}{index}");
+                }
+
+                self.schemas.insert(unique_name.clone(), schema.clone());
+                hoisted.push((value, unique_name.clone()));
+                unique_name
+            }
+        };
+
+        *schema = ApiSchema::new();
+        schema.reference(Some(name), false);
+    }
 }
 
 #[derive(Serialize, Debug)]
@@ -95,17 +228,34 @@ impl<'v> ApiPath {
         }
     }
 
-    pub fn add_operation(&mut self, method: &str) -> &Rc<RefCell<ApiPathOperation>> {
+    fn operations(&self) -> Vec<(&str, &Rc<RefCell<ApiPathOperation>>)> {
+        [
+            ("get", &self.get),
+            ("put", &self.put),
+            ("post", &self.post),
+            ("delete", &self.delete),
+            ("options", &self.options),
+            ("head", &self.head),
+            ("patch", &self.patch),
+            ("trace", &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| operation.as_ref().map(|operation| (method, operation)))
+        .collect()
+    }
+
+    pub fn add_operation(&mut self, method: &str) -> Option<&Rc<RefCell<ApiPathOperation>>> {
+        let operation = Rc::new(RefCell::new(ApiPathOperation::new()));
         match method.to_lowercase().as_str() {
-            "get" => self.get.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            "put" => self.put.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            "post" => self.post.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            "delete" => self.delete.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            "options" => self.options.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            "head" => self.head.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            "patch" => self.patch.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            "trace" => self.trace.insert(Rc::new(RefCell::new(ApiPathOperation::new()))),
-            other => panic!("Unsupported http method '{}'", other),
+            "get" => Some(self.get.insert(operation)),
+            "put" => Some(self.put.insert(operation)),
+            "post" => Some(self.post.insert(operation)),
+            "delete" => Some(self.delete.insert(operation)),
+            "options" => Some(self.options.insert(operation)),
+            "head" => Some(self.head.insert(operation)),
+            "patch" => Some(self.patch.insert(operation)),
+            "trace" => Some(self.trace.insert(operation)),
+            _ => None,
         }
     }
 }
@@ -115,11 +265,13 @@ pub struct ApiPathOperation {
     #[serde(rename = "requestBody", skip_serializing_if = "Option::is_none")]
     body_parameter: Option<ApiParam>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    examples: Option<HashMap<String, ApiSchema>>,
+    examples: Option<IndexMap<String, ApiSchema>>,
+    #[serde(rename = "operationId", skip_serializing_if = "Option::is_none")]
+    operation_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     parameters: Option<Vec<ApiParam>>,
-    #[serde(skip_serializing_if = "HashMap::is_empty")]
-    responses: HashMap<String, ApiResponse>,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    responses: IndexMap<String, ApiResponse>,
     #[serde(skip_serializing_if = "Option::is_none")]
     tags: Option<Vec<String>>,
 }
@@ -129,7 +281,8 @@ impl ApiPathOperation {
         ApiPathOperation {
             body_parameter: None,
             examples: None,
-            responses: HashMap::new(),
+            operation_id: None,
+            responses: IndexMap::new(),
             parameters: None,
             tags: None,
         }
@@ -140,9 +293,22 @@ impl ApiPathOperation {
         self
     }
 
+    pub(crate) fn operation_id(&mut self, operation_id: Option<String>) -> &mut ApiPathOperation {
+        self.operation_id = operation_id;
+        self
+    }
+
     pub(crate) fn response(&mut self, status_code: &str, description: &str) -> &mut ApiResponse {
-        let response = ApiResponse::new(description);
-        self.responses.entry(status_code.into()).or_insert(response)
+        let response = self
+            .responses
+            .entry(status_code.into())
+            .or_insert_with(|| ApiResponse::new(description));
+        response.description(description);
+        response
+    }
+
+    pub(crate) fn has_responses(&self) -> bool {
+        !self.responses.is_empty()
     }
 
     pub(crate) fn param(&mut self, name: &str, location: &str) -> &mut ApiParam {
@@ -164,11 +330,12 @@ pub struct ApiPathParameter {}
 
 #[derive(Clone, Debug, Serialize)]
 pub struct ApiResponse {
-    description: String,
+    #[serde(rename = "description", serialize_with = "serialize_descriptions")]
+    descriptions: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    headers: Option<HashMap<String, ApiParam>>,
+    headers: Option<IndexMap<String, ApiParam>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    content: Option<HashMap<String, ApiContent>>,
+    content: Option<IndexMap<String, ApiContent>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     links: Option<Vec<ApiSchema>>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -179,7 +346,7 @@ impl ApiResponse {
     fn new(description: &str) -> Self {
         ApiResponse {
             content: None,
-            description: description.to_string(),
+            descriptions: vec![description.to_string()],
             examples: None,
             headers: None,
             links: None,
@@ -192,6 +359,20 @@ impl ApiResponse {
             .entry(media_type.unwrap_or("application/json").to_string())
             .or_insert(ApiContent::new())
     }
+
+    fn description(&mut self, description: &str) -> &mut ApiResponse {
+        if !self.descriptions.iter().any(|d| d.eq(description)) {
+            self.descriptions.push(description.to_string());
+        }
+        self
+    }
+}
+
+fn serialize_descriptions<S>(descriptions: &[String], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&descriptions.join(" or "))
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -200,15 +381,56 @@ pub struct ApiContent {
     schema: Option<ApiSchema>,
     #[serde(skip_serializing_if = "Option::is_none")]
     example: Option<Box<ApiSchema>>,
+    #[serde(skip)]
+    merged: bool,
 }
 impl ApiContent {
     pub fn new() -> Self {
         ApiContent {
             schema: None,
             example: None,
+            merged: false,
         }
     }
 
+    pub(crate) fn add_schema(&mut self, schema: ApiSchema) -> &mut ApiContent {
+        let existing = match self.schema.take() {
+            Some(existing) => existing,
+            None => {
+                self.schema = Some(schema);
+                return self;
+            }
+        };
+
+        let mut merged = match self.merged {
+            true => existing,
+            false => {
+                let mut merged = ApiSchema::new();
+                merged.one_of().push(existing);
+                merged
+            }
+        };
+
+        let value = serde_json::to_value(&schema).ok();
+        let duplicate = merged
+            .one_of()
+            .iter()
+            .any(|variant| serde_json::to_value(variant).ok() == value);
+
+        if !duplicate {
+            merged.one_of().push(schema);
+        }
+
+        if merged.one_of().len() == 1 {
+            self.schema = merged.one_of.take().and_then(|mut variants| variants.pop());
+        } else {
+            self.merged = true;
+            self.schema = Some(merged);
+        }
+
+        self
+    }
+
     pub fn schema(&mut self) -> &mut ApiSchema {
         self.schema.get_or_insert(ApiSchema::new())
     }
@@ -227,15 +449,20 @@ impl ApiContent {
 pub struct ApiSchema {
     any_of: Option<Vec<ApiSchema>>,
     all_of: Option<Vec<ApiSchema>>,
+    one_of: Option<Vec<ApiSchema>>,
     data_type: Option<String>,
-    enums: Option<Vec<String>>,
+    discriminator: Option<ApiDiscriminator>,
+    enums: Option<Vec<Value>>,
     format: Option<String>,
-    id: Option<String>,
     is_example: bool,
     items: Option<Box<ApiSchema>>,
-    properties: Option<HashMap<String, ApiSchema>>,
+    min_items: Option<usize>,
+    pattern: Option<String>,
+    prefix_items: Option<Vec<ApiSchema>>,
+    properties: Option<IndexMap<String, ApiSchema>>,
+    read_only: Option<bool>,
     reference: Option<String>,
-    required: HashSet<String>,
+    required: BTreeSet<String>,
 }
 
 impl Serialize for ApiSchema {
@@ -243,7 +470,7 @@ impl Serialize for ApiSchema {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("ApiSchema", 10)?;
+        let mut state = serializer.serialize_struct("ApiSchema", 15)?;
 
         if let Some(ref any_of) = self.any_of {
             state.serialize_field("anyOf", any_of)?;
@@ -251,21 +478,39 @@ impl Serialize for ApiSchema {
         if let Some(ref all_of) = self.all_of {
             state.serialize_field("allOf", all_of)?;
         }
+        if let Some(ref one_of) = self.one_of {
+            state.serialize_field("oneOf", one_of)?;
+        }
+        if let Some(ref discriminator) = self.discriminator {
+            state.serialize_field("discriminator", discriminator)?;
+        }
         if let Some(ref enums) = self.enums {
             state.serialize_field("enum", enums)?;
         }
         if let Some(ref format) = self.format {
             state.serialize_field("format", format)?;
         }
-        if let Some(ref id) = self.id {
-            state.serialize_field("$id", &format!("#/components/schemas/{id}"))?;
+        match (&self.items, &self.prefix_items) {
+            (Some(items), _) => state.serialize_field("items", items)?,
+            // a tuple without a rest element has no items past its prefix
+            (None, Some(_)) => state.serialize_field("items", &false)?,
+            (None, None) => {}
+        }
+        if let Some(ref min_items) = self.min_items {
+            state.serialize_field("minItems", min_items)?;
+        }
+        if let Some(ref pattern) = self.pattern {
+            state.serialize_field("pattern", pattern)?;
         }
-        if let Some(ref items) = self.items {
-            state.serialize_field("items", items)?;
+        if let Some(ref prefix_items) = self.prefix_items {
+            state.serialize_field("prefixItems", prefix_items)?;
         }
         if let Some(ref properties) = self.properties {
             state.serialize_field("properties", properties)?;
         }
+        if let Some(ref read_only) = self.read_only {
+            state.serialize_field("readOnly", read_only)?;
+        }
         if !self.required.is_empty() {
             state.serialize_field("required", &self.required)?;
         }
@@ -285,36 +530,39 @@ impl Serialize for ApiSchema {
     }
 }
 
-impl ApiSchema {
-    pub fn new() -> Self {
-        ApiSchema {
-            any_of: None,
-            all_of: None,
-            data_type: None,
-            enums: None,
-            format: None,
-            id: None,
-            is_example: false,
-            items: None,
-            properties: None,
-            reference: None,
-            required: HashSet::new(),
-        }
+fn to_literal_value(value: &str, data_type: &str) -> Value {
+    match data_type {
+        "number" => match value.parse::<i64>() {
+            Ok(number) => Value::from(number),
+            Err(_) => value
+                .parse::<f64>()
+                .map(Value::from)
+                .unwrap_or_else(|_| Value::String(value.to_string())),
+        },
+        "boolean" => Value::Bool(value.eq("true")),
+        _ => Value::String(value.to_string()),
     }
+}
 
-    pub fn with_id(id: &str) -> Self {
+impl ApiSchema {
+    pub fn new() -> Self {
         ApiSchema {
-            id: Some(id.to_string()),
             any_of: None,
             all_of: None,
+            one_of: None,
             data_type: None,
+            discriminator: None,
             enums: None,
             format: None,
             is_example: false,
             items: None,
+            min_items: None,
+            pattern: None,
+            prefix_items: None,
             properties: None,
+            read_only: None,
             reference: None,
-            required: HashSet::new(),
+            required: BTreeSet::new(),
         }
     }
 
@@ -330,12 +578,39 @@ impl ApiSchema {
         self
     }
 
+    pub fn pattern(&mut self, pattern: Option<String>) -> &mut ApiSchema {
+        self.pattern = pattern;
+        self
+    }
+
+    pub fn read_only(&mut self, read_only: bool) -> &mut ApiSchema {
+        self.read_only = Some(read_only);
+        self
+    }
+
     pub fn reference(&mut self, reference: Option<String>, is_example: bool) -> &mut ApiSchema {
         self.is_example = is_example;
         self.reference = reference.clone();
         self
     }
 
+    pub(crate) fn reference_name(&self) -> Option<&str> {
+        self.reference.as_deref()
+    }
+
+    pub(crate) fn discriminator(&mut self, property_name: &str, mapping: Vec<(String, String)>) -> &mut ApiSchema {
+        let mapping = mapping
+            .into_iter()
+            .map(|(value, reference)| (value, format!("#/components/schemas/{reference}")))
+            .collect();
+
+        self.discriminator = Some(ApiDiscriminator {
+            property_name: property_name.to_string(),
+            mapping,
+        });
+        self
+    }
+
     pub fn required_field(&mut self, name: &str) -> &mut ApiSchema {
         self.required.insert(name.to_string());
         self
@@ -343,7 +618,7 @@ impl ApiSchema {
 
     pub fn property(&mut self, name_text: &str) -> &mut ApiSchema {
         self.properties
-            .get_or_insert(HashMap::new())
+            .get_or_insert(IndexMap::new())
             .entry(name_text.to_string())
             .or_insert(ApiSchema::new())
     }
@@ -352,8 +627,25 @@ impl ApiSchema {
         self.items.get_or_insert(Box::new(ApiSchema::new()))
     }
 
+    pub(crate) fn prefix_items(&mut self, prefix_items: Vec<ApiSchema>, min_items: usize) -> &mut ApiSchema {
+        self.prefix_items = Some(prefix_items);
+        self.min_items = Some(min_items);
+        self
+    }
+
     pub(crate) fn enum_value(&mut self, value: &str) {
-        self.enums.get_or_insert(Vec::new()).push(value.to_string());
+        self.enums
+            .get_or_insert(Vec::new())
+            .push(Value::String(value.to_string()));
+    }
+
+    // literals keep their json type, so `1 | 2` is `[1, 2]` rather than `["1", "2"]`
+    pub(crate) fn literal_value(&mut self, value: &str, data_type: &str) {
+        let value = to_literal_value(value, data_type);
+        let enums = self.enums.get_or_insert(Vec::new());
+        if !enums.contains(&value) {
+            enums.push(value);
+        }
     }
 
     pub(crate) fn any_of(&mut self) -> &mut Vec<ApiSchema> {
@@ -364,6 +656,35 @@ impl ApiSchema {
         self.all_of.get_or_insert(vec![])
     }
 
+    pub(crate) fn one_of(&mut self) -> &mut Vec<ApiSchema> {
+        self.one_of.get_or_insert(vec![])
+    }
+
+    pub(crate) fn union(&mut self, schemas: Vec<ApiSchema>) -> &mut ApiSchema {
+        let mut variants: Vec<ApiSchema> = vec![];
+        for schema in schemas {
+            let value = serde_json::to_value(&schema).ok();
+            if !variants
+                .iter()
+                .any(|variant| serde_json::to_value(variant).ok() == value)
+            {
+                variants.push(schema);
+            }
+        }
+
+        if variants.len() == 1 {
+            *self = variants.remove(0);
+        } else if variants.len() > 1 {
+            self.any_of().extend(variants);
+        }
+
+        self
+    }
+
+    fn is_anonymous_object(&self) -> bool {
+        self.reference.is_none() && (self.properties.is_some() || self.data_type.as_deref() == Some("object"))
+    }
+
     pub(crate) fn has_enums(&self) -> bool {
         if let Some(enums) = &self.enums {
             enums.len() > 0
@@ -373,6 +694,14 @@ impl ApiSchema {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct ApiDiscriminator {
+    #[serde(rename = "propertyName")]
+    property_name: String,
+    #[serde(skip_serializing_if = "IndexMap::is_empty")]
+    mapping: IndexMap<String, String>,
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct ApiParam {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -380,23 +709,62 @@ pub struct ApiParam {
     #[serde(rename = "in", skip_serializing_if = "Option::is_none")]
     location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    content: Option<HashMap<String, ApiContent>>,
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    style: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    explode: Option<bool>,
+    #[serde(rename = "allowReserved", skip_serializing_if = "Option::is_none")]
+    allow_reserved: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schema: Option<ApiSchema>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<IndexMap<String, ApiContent>>,
     required: bool,
 }
 
 impl ApiParam {
     fn new(name: Option<&str>, location: Option<&str>) -> ApiParam {
         ApiParam {
+            allow_reserved: None,
             content: None,
+            description: None,
+            explode: None,
             location: location.map(|l| l.to_string()),
             name: name.map(|n| n.to_string()),
             required: false,
+            schema: None,
+            style: None,
         }
     }
 
+    pub(crate) fn schema(&mut self) -> &mut ApiSchema {
+        self.schema.get_or_insert(ApiSchema::new())
+    }
+
+    pub(crate) fn description(&mut self, description: Option<String>) -> &mut ApiParam {
+        self.description = description;
+        self
+    }
+
+    pub(crate) fn style(&mut self, style: Option<String>) -> &mut ApiParam {
+        self.style = style;
+        self
+    }
+
+    pub(crate) fn explode(&mut self, explode: Option<bool>) -> &mut ApiParam {
+        self.explode = explode;
+        self
+    }
+
+    pub(crate) fn allow_reserved(&mut self, allow_reserved: Option<bool>) -> &mut ApiParam {
+        self.allow_reserved = allow_reserved;
+        self
+    }
+
     pub(crate) fn content(&mut self, media_type: Option<&str>) -> &mut ApiContent {
         self.content
-            .get_or_insert(HashMap::new())
+            .get_or_insert(IndexMap::new())
             .entry(media_type.unwrap_or("application/json").to_string())
             .or_insert(ApiContent::new())
     }
@@ -410,6 +778,7 @@ impl ApiParam {
 #[derive(Debug, Clone, Default)]
 pub struct PathOptions {
     pub method: Option<String>,
+    pub operation_id: Option<String>,
     pub path: Option<String>,
     pub tags: Option<Vec<String>>,
 }
@@ -418,18 +787,105 @@ impl PathOptions {
     pub(crate) fn new() -> Self {
         PathOptions {
             method: None,
+            operation_id: None,
             path: None,
             tags: None,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterForm {
+    Content,
+    Schema,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OpenApiVersion {
+    V3_0,
+    V3_1,
+}
+
+impl OpenApiVersion {
+    pub(crate) fn parse(version: &str) -> Self {
+        match version == "3.0" || version.starts_with("3.0.") {
+            true => OpenApiVersion::V3_0,
+            false => OpenApiVersion::V3_1,
+        }
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        match self {
+            OpenApiVersion::V3_0 => "3.0.3",
+            OpenApiVersion::V3_1 => "3.1.0",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Json,
+    Yaml,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnresolvedReferences {
+    Empty,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SchemaNameCollision {
+    Error,
+    Prefix,
+    Suffix,
+}
+
+#[derive(Debug, Clone)]
+pub struct OpenApiOptions {
+    pub parameter_form: ParameterForm,
+    pub infer_responses: bool,
+    pub return_type_responses: bool,
+    pub return_type_description: String,
+    pub hoist_schemas: bool,
+    pub response_schema_name: String,
+    pub body_schema_name: String,
+    pub schema_name_collision: SchemaNameCollision,
+    pub format: OutputFormat,
+    pub indent: usize,
+    pub version: Option<OpenApiVersion>,
+    pub fail_on_invalid: bool,
+    pub unresolved_references: UnresolvedReferences,
+    pub compiler_options: CompilerOptions,
+}
+
+impl OpenApiOptions {
+    pub(crate) fn new() -> Self {
+        OpenApiOptions {
+            parameter_form: ParameterForm::Schema,
+            infer_responses: false,
+            return_type_responses: true,
+            return_type_description: "OK".into(),
+            hoist_schemas: false,
+            response_schema_name: "{OperationId}{Status}Response".into(),
+            body_schema_name: "{OperationId}Body".into(),
+            schema_name_collision: SchemaNameCollision::Suffix,
+            format: OutputFormat::Json,
+            indent: 0,
+            version: None,
+            fail_on_invalid: false,
+            unresolved_references: UnresolvedReferences::Empty,
+            compiler_options: CompilerOptions::default(),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ResponseOptions {
     pub description: Option<String>,
     pub example: Option<String>,
     pub status_code: Option<String>,
-    pub media_type: Option<String>,
+    pub media_types: Vec<String>,
 }
 impl ResponseOptions {
     pub(crate) fn new() -> Self {
@@ -437,7 +893,7 @@ impl ResponseOptions {
             description: None,
             example: None,
             status_code: None,
-            media_type: None,
+            media_types: vec![],
         }
     }
 }
@@ -1,13 +1,36 @@
-use std::{path::PathBuf, rc::Rc};
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
 use es_resolve::{EsResolver, TargetEnv};
-use swc_ecma_ast::{Callee, ExportSpecifier, Expr, ImportSpecifier, ModuleExportName, Pat, TsEntityName, TsType};
+use swc_ecma_ast::{
+    AssignOp, Callee, ExportSpecifier, Expr, ImportSpecifier, Lit, MemberExpr, MemberProp, ModuleExportName,
+    ObjectPatProp, Pat, PatOrExpr, Prop, PropName, PropOrSpread, TsEntityName, TsModuleRef, TsType,
+};
 
 use crate::typescript::{NodeKind, SchemyNode};
 
 use super::{declaration_table::Declaration, Store};
 
 impl Store {
+    pub(crate) fn parse_module(&mut self, file_path: &str) -> Rc<SchemyNode<'static>> {
+        let is_cached = self.modules.contains(file_path);
+        let root = self.modules.parse(file_path);
+
+        if !is_cached {
+            if let Some(comments) = self.modules.comments(file_path) {
+                self.add_comments(file_path, comments);
+            }
+
+            for child in root.children() {
+                self.store_declaration_maybe(child, file_path);
+            }
+        }
+
+        root
+    }
+
     pub fn add_child_scope(&mut self, file_path: &str) -> () {
         self.symbol_tables.add_child_scope(file_path)
     }
@@ -41,14 +64,97 @@ impl Store {
             }
             NodeKind::ExportDefaultExpr(_) => {
                 for child in root.children() {
-                    self.store_default_declaration(child, file_path)
+                    self.store_export_declaration("default", child, file_path)
                 }
             }
+            NodeKind::TsExportAssignment(raw) => {
+                let expression = root.to_child(NodeKind::Expr(&raw.expr));
+                for child in expression.children() {
+                    self.store_export_declaration("default", child, file_path)
+                }
+            }
+            NodeKind::TsImportEquals(raw) => match &raw.module_ref {
+                TsModuleRef::TsExternalModuleRef(module_ref) => {
+                    if let Some(module_path) = resolve_module(&module_ref.expr.value, file_path) {
+                        self.symbol_tables.insert(
+                            file_path,
+                            raw.id.sym.to_string(),
+                            Declaration::Import {
+                                name: String::from("default"),
+                                source_file_name: get_source_file_name(&module_path),
+                            },
+                        )
+                    }
+                }
+                TsModuleRef::TsEntityName(TsEntityName::Ident(identifier)) => self.symbol_tables.insert(
+                    file_path,
+                    raw.id.sym.to_string(),
+                    Declaration::Alias {
+                        to: identifier.sym.to_string(),
+                    },
+                ),
+                _ => {}
+            },
+            NodeKind::ExprStmt(raw) => match &*raw.expr {
+                Expr::Assign(assign) if assign.op == AssignOp::Assign => {
+                    let target = match &assign.left {
+                        PatOrExpr::Expr(expr) => get_commonjs_export(expr),
+                        PatOrExpr::Pat(pat) => match &**pat {
+                            Pat::Expr(expr) => get_commonjs_export(expr),
+                            _ => None,
+                        },
+                    };
+
+                    match target {
+                        Some(None) => match &*assign.right {
+                            Expr::Object(object) => {
+                                for prop in &object.props {
+                                    match prop {
+                                        PropOrSpread::Prop(prop) => match &**prop {
+                                            Prop::KeyValue(key_value) => match get_prop_name(&key_value.key) {
+                                                Some(name) => {
+                                                    let value = root.to_child(NodeKind::Expr(&key_value.value));
+                                                    self.store_commonjs_export(&name, value, file_path)
+                                                }
+                                                None => {}
+                                            },
+                                            // { Name } exports the binding of the same name, which is already declared
+                                            Prop::Shorthand(_) => {}
+                                            _ => {}
+                                        },
+                                        _ => {}
+                                    }
+                                }
+                            }
+                            _ => {
+                                let value = root.to_child(NodeKind::Expr(&assign.right));
+                                self.store_commonjs_export("default", value, file_path)
+                            }
+                        },
+                        Some(Some(name)) => {
+                            let value = root.to_child(NodeKind::Expr(&assign.right));
+                            self.store_commonjs_export(&name, value, file_path)
+                        }
+                        None => {}
+                    }
+                }
+                _ => {}
+            },
             NodeKind::Decl(_) => {
                 for child in root.children() {
                     self.store_declaration_maybe(child, file_path)
                 }
             }
+            NodeKind::VarDecl(_) => {
+                for child in root.children() {
+                    self.store_declaration_maybe(child, file_path)
+                }
+            }
+            NodeKind::FnDecl(raw) => {
+                let name = raw.ident.sym.to_string();
+                self.symbol_tables
+                    .insert(file_path, name, Declaration::Type { node: root.clone() })
+            }
             NodeKind::ClassDecl(raw) => {
                 let name = raw.ident.sym.to_string();
                 self.symbol_tables
@@ -80,6 +186,11 @@ impl Store {
                             Declaration::Type { node: class.clone() },
                         )
                     }
+                    swc_ecma_ast::DefaultDecl::Fn(raw_fn) => {
+                        let function = root.to_child(NodeKind::FnExpr(raw_fn));
+                        self.symbol_tables
+                            .insert(file_path, "default".into(), Declaration::Type { node: function })
+                    }
                     swc_ecma_ast::DefaultDecl::TsInterfaceDecl(raw_int) => {
                         let interface = root.to_child(NodeKind::TsInterfaceDecl(&*raw_int));
                         self.symbol_tables.insert(
@@ -90,7 +201,6 @@ impl Store {
                             },
                         )
                     }
-                    _ => {}
                 };
             }
             NodeKind::ImportDecl(raw) => {
@@ -106,7 +216,7 @@ impl Store {
                                         name,
                                         Declaration::Import {
                                             name: String::from("default"),
-                                            source_file_name: module_path.replace(".js", ".d.ts"),
+                                            source_file_name: get_source_file_name(&module_path),
                                         },
                                     )
                                 }
@@ -118,12 +228,18 @@ impl Store {
                             match EsResolver::new(&src, &PathBuf::from(file_path), TargetEnv::Node).resolve() {
                                 Ok(module_path) => {
                                     let name = &raw_specifier.local.sym;
+                                    let imported_name = match &raw_specifier.imported {
+                                        Some(ModuleExportName::Ident(identifier)) => &identifier.sym,
+                                        Some(ModuleExportName::Str(identifier)) => &identifier.value,
+                                        None => name,
+                                    };
+
                                     self.symbol_tables.insert(
                                         file_path,
                                         name.to_string(),
                                         Declaration::Import {
-                                            name: name.to_string(),
-                                            source_file_name: module_path.replace(".js", ".d.ts"),
+                                            name: imported_name.to_string(),
+                                            source_file_name: get_source_file_name(&module_path),
                                         },
                                     )
                                 }
@@ -159,7 +275,7 @@ impl Store {
                                                     exported_name.to_string(),
                                                     Declaration::Import {
                                                         name: type_name.to_string(),
-                                                        source_file_name: module_path.replace(".js", ".d.ts"),
+                                                        source_file_name: get_source_file_name(&module_path),
                                                     },
                                                 )
                                             } else {
@@ -168,7 +284,7 @@ impl Store {
                                                     type_name.to_string(),
                                                     Declaration::Import {
                                                         name: type_name.to_string(),
-                                                        source_file_name: module_path.replace(".js", ".d.ts"),
+                                                        source_file_name: get_source_file_name(&module_path),
                                                     },
                                                 )
                                             }
@@ -184,6 +300,12 @@ impl Store {
                 }
             }
             NodeKind::VarDeclarator(raw) => {
+                let required_module = raw.init.as_ref().and_then(|init| get_required_module(init, file_path));
+                if let Some((imported_name, module_path)) = required_module {
+                    self.store_required_module(&raw.name, &imported_name, &module_path, file_path);
+                    return;
+                }
+
                 match &raw.name {
                     Pat::Ident(identifier) => {
                         let name = identifier.id.sym.to_string();
@@ -206,13 +328,41 @@ impl Store {
                                         node: root.to_child(NodeKind::TsTypeLit(raw_type)).clone(),
                                     },
                                 ),
-                                _ => {}
+                                _ => self.symbol_tables.insert(
+                                    file_path,
+                                    name.to_string(),
+                                    Declaration::Value {
+                                        node: root.to_child(NodeKind::TsTypeAnnotation(type_annotation)),
+                                    },
+                                ),
                             },
                             None => match &raw.init {
-                                Some(initializer) => {
-                                    let node = root.to_child(NodeKind::Expr(initializer));
-                                    self.store_variable(&name, node, file_path);
-                                }
+                                Some(initializer) => match &**initializer {
+                                    Expr::Array(_)
+                                    | Expr::Arrow(_)
+                                    | Expr::Await(_)
+                                    | Expr::Call(_)
+                                    | Expr::Cond(_)
+                                    | Expr::Fn(_)
+                                    | Expr::Lit(_)
+                                    | Expr::Member(_)
+                                    | Expr::Object(_)
+                                    | Expr::Paren(_)
+                                    | Expr::Tpl(_)
+                                    | Expr::TsConstAssertion(_)
+                                    | Expr::TsNonNull(_)
+                                    | Expr::TsSatisfies(_) => self.symbol_tables.insert(
+                                        file_path,
+                                        name.to_string(),
+                                        Declaration::Value {
+                                            node: root.to_child(NodeKind::Expr(initializer)),
+                                        },
+                                    ),
+                                    _ => {
+                                        let node = root.to_child(NodeKind::Expr(initializer));
+                                        self.store_variable(&name, node, file_path);
+                                    }
+                                },
                                 None => {}
                             },
                         }
@@ -224,13 +374,78 @@ impl Store {
         }
     }
 
-    fn store_default_declaration(&mut self, root: Rc<SchemyNode<'static>>, file_path: &str) -> () {
+    fn store_required_module(&mut self, name: &Pat, imported_name: &str, module_path: &str, file_path: &str) -> () {
+        match name {
+            Pat::Ident(identifier) => self.symbol_tables.insert(
+                file_path,
+                identifier.id.sym.to_string(),
+                Declaration::Import {
+                    name: imported_name.to_string(),
+                    source_file_name: get_source_file_name(module_path),
+                },
+            ),
+            Pat::Object(object) if imported_name.eq("default") => {
+                for prop in &object.props {
+                    let (local_name, exported_name) = match prop {
+                        ObjectPatProp::Assign(assign) => (assign.key.sym.to_string(), assign.key.sym.to_string()),
+                        ObjectPatProp::KeyValue(key_value) => {
+                            match (&*key_value.value, get_prop_name(&key_value.key)) {
+                                (Pat::Ident(identifier), Some(exported_name)) => {
+                                    (identifier.id.sym.to_string(), exported_name)
+                                }
+                                _ => continue,
+                            }
+                        }
+                        ObjectPatProp::Rest(_) => continue,
+                    };
+
+                    self.symbol_tables.insert(
+                        file_path,
+                        local_name,
+                        Declaration::Import {
+                            name: exported_name,
+                            source_file_name: get_source_file_name(module_path),
+                        },
+                    )
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn store_commonjs_export(&mut self, name: &str, root: Rc<SchemyNode<'static>>, file_path: &str) -> () {
+        let required_module = match root.kind {
+            NodeKind::Expr(raw) => get_required_module(raw, file_path),
+            _ => None,
+        };
+
+        match required_module {
+            Some((exported_name, module_path)) => self.symbol_tables.insert(
+                file_path,
+                name.to_string(),
+                Declaration::Export {
+                    name: exported_name,
+                    source_file_name: get_source_file_name(&module_path),
+                },
+            ),
+            None => {
+                for child in root.children() {
+                    match child.kind {
+                        NodeKind::Ident(raw_ident) if raw_ident.sym.eq(name) => {}
+                        _ => self.store_export_declaration(name, child, file_path),
+                    }
+                }
+            }
+        }
+    }
+
+    fn store_export_declaration(&mut self, name: &str, root: Rc<SchemyNode<'static>>, file_path: &str) -> () {
         match root.kind {
             NodeKind::CallExpr(raw_call) => match &raw_call.callee {
                 Callee::Expr(raw_callee) => match &**raw_callee {
                     Expr::Ident(raw_ident) => self.symbol_tables.insert(
                         file_path,
-                        "default".into(),
+                        name.to_string(),
                         Declaration::Alias {
                             to: raw_ident.sym.to_string(),
                         },
@@ -241,16 +456,16 @@ impl Store {
             },
             NodeKind::ArrayLit(_) => {
                 self.symbol_tables
-                    .insert(file_path, "default".into(), Declaration::Type { node: root.clone() })
+                    .insert(file_path, name.to_string(), Declaration::Type { node: root.clone() })
             }
             NodeKind::ObjectLit(_) => {
                 self.symbol_tables
-                    .insert(file_path, "default".into(), Declaration::Type { node: root.clone() })
+                    .insert(file_path, name.to_string(), Declaration::Type { node: root.clone() })
             }
             NodeKind::NewExpr(expr) => match &*expr.callee {
                 Expr::Ident(raw_ident) => self.symbol_tables.insert(
                     file_path,
-                    "default".into(),
+                    name.to_string(),
                     Declaration::Alias {
                         to: raw_ident.sym.to_string(),
                     },
@@ -259,19 +474,19 @@ impl Store {
             },
             NodeKind::Ident(raw_ident) => self.symbol_tables.insert(
                 file_path,
-                "default".into(),
+                name.to_string(),
                 Declaration::Alias {
                     to: raw_ident.sym.to_string(),
                 },
             ),
             NodeKind::ArrowExpr(_) => {
                 self.symbol_tables
-                    .insert(file_path, "default".into(), Declaration::Type { node: root })
+                    .insert(file_path, name.to_string(), Declaration::Type { node: root })
             }
             NodeKind::ClassExpr(expr) => match &expr.ident {
                 Some(raw_ident) => self.symbol_tables.insert(
                     file_path,
-                    "default".into(),
+                    name.to_string(),
                     Declaration::Alias {
                         to: raw_ident.sym.to_string(),
                     },
@@ -282,7 +497,7 @@ impl Store {
                 TsType::TsTypeRef(raw_ref) => match &raw_ref.type_name {
                     TsEntityName::Ident(raw_ident) => self.symbol_tables.insert(
                         file_path,
-                        "default".into(),
+                        name.to_string(),
                         Declaration::Alias {
                             to: raw_ident.sym.to_string(),
                         },
@@ -294,7 +509,7 @@ impl Store {
             NodeKind::TsInstantiationExpr(raw_expr) => match &*raw_expr.expr {
                 Expr::Ident(raw_ident) => self.symbol_tables.insert(
                     file_path,
-                    "default".into(),
+                    name.to_string(),
                     Declaration::Alias {
                         to: raw_ident.sym.to_string(),
                     },
@@ -331,3 +546,119 @@ impl Store {
         }
     }
 }
+
+// prefer the declaration file that ships alongside a javascript module, when there is one
+fn get_source_file_name(module_path: &str) -> String {
+    for (extension, declaration_extension) in [(".js", ".d.ts"), (".mjs", ".d.mts"), (".cjs", ".d.cts")] {
+        if let Some(stem) = module_path.strip_suffix(extension) {
+            let declaration_path = format!("{}{}", stem, declaration_extension);
+            if Path::new(&declaration_path).exists() {
+                return declaration_path;
+            }
+        }
+    }
+
+    module_path.to_string()
+}
+
+fn resolve_module(src: &str, file_path: &str) -> Option<String> {
+    EsResolver::new(src, &PathBuf::from(file_path), TargetEnv::Node)
+        .resolve()
+        .ok()
+}
+
+// require('./module') imports the default export, require('./module').Name imports a named one
+fn get_required_module(expr: &Expr, file_path: &str) -> Option<(String, String)> {
+    match expr {
+        Expr::Call(call) => match (&call.callee, call.args.first()) {
+            (Callee::Expr(callee), Some(arg)) if call.args.len() == 1 => match (&**callee, &*arg.expr) {
+                (Expr::Ident(identifier), Expr::Lit(Lit::Str(src))) if identifier.sym.eq("require") => {
+                    resolve_module(&src.value, file_path).map(|module_path| (String::from("default"), module_path))
+                }
+                _ => None,
+            },
+            _ => None,
+        },
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => match get_required_module(obj, file_path) {
+            Some((name, module_path)) if name.eq("default") => Some((prop.sym.to_string(), module_path)),
+            _ => None,
+        },
+        Expr::Paren(paren) => get_required_module(&paren.expr, file_path),
+        _ => None,
+    }
+}
+
+// module.exports yields Some(None), exports.Name and module.exports.Name yield Some(Some(Name))
+fn get_commonjs_export(expr: &Expr) -> Option<Option<String>> {
+    let is_module_exports = |expr: &Expr| match expr {
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => prop.sym.eq("exports") && matches!(&**obj, Expr::Ident(identifier) if identifier.sym.eq("module")),
+        _ => false,
+    };
+
+    if is_module_exports(expr) {
+        return Some(None);
+    }
+
+    match expr {
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) => match &**obj {
+            Expr::Ident(identifier) if identifier.sym.eq("exports") => Some(Some(prop.sym.to_string())),
+            obj if is_module_exports(obj) => Some(Some(prop.sym.to_string())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// the names assigned by module.exports = { ... }, module.exports.Name = ... and exports.Name = ...
+pub(crate) fn get_commonjs_export_names(expr: &Expr) -> Vec<String> {
+    let assign = match expr {
+        Expr::Assign(assign) if assign.op == AssignOp::Assign => assign,
+        _ => return vec![],
+    };
+
+    let target = match &assign.left {
+        PatOrExpr::Expr(expr) => get_commonjs_export(expr),
+        PatOrExpr::Pat(pat) => match &**pat {
+            Pat::Expr(expr) => get_commonjs_export(expr),
+            _ => None,
+        },
+    };
+
+    match (target, &*assign.right) {
+        (Some(None), Expr::Object(object)) => object
+            .props
+            .iter()
+            .filter_map(|prop| match prop {
+                PropOrSpread::Prop(prop) => match &**prop {
+                    Prop::KeyValue(key_value) => get_prop_name(&key_value.key),
+                    Prop::Shorthand(identifier) => Some(identifier.sym.to_string()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect(),
+        (Some(None), _) => vec![String::from("default")],
+        (Some(Some(name)), _) => vec![name],
+        (None, _) => vec![],
+    }
+}
+
+fn get_prop_name(prop_name: &PropName) -> Option<String> {
+    match prop_name {
+        PropName::Ident(identifier) => Some(identifier.sym.to_string()),
+        PropName::Str(value) => Some(value.value.to_string()),
+        _ => None,
+    }
+}
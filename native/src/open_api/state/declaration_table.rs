@@ -10,10 +10,20 @@ use crate::typescript::SchemyNode;
 #[derive(Debug, Default)]
 struct Scope {
     symbols: BTreeMap<String, Declaration>,
+    values: BTreeMap<String, Declaration>,
     children: Option<Vec<Rc<RefCell<Scope>>>>,
     parent: Option<Rc<RefCell<Scope>>>,
 }
 
+impl Scope {
+    fn get(&self, reference: &str, is_value: bool) -> Option<&Declaration> {
+        match is_value {
+            true => self.values.get(reference).or_else(|| self.symbols.get(reference)),
+            false => self.symbols.get(reference).or_else(|| self.values.get(reference)),
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct DeclarationTables {
     tables: BTreeMap<String, DeclarationTable>,
@@ -49,7 +59,14 @@ impl<'n> DeclarationTables {
         self.tables
             .entry(file_path.to_owned())
             .or_insert_with(Default::default)
-            .get_root_declaration(reference)
+            .get_root_declaration(reference, false)
+    }
+
+    pub(crate) fn get_root_value_declaration(&mut self, file_path: &str, reference: &str) -> Option<Declaration> {
+        self.tables
+            .entry(file_path.to_owned())
+            .or_insert_with(Default::default)
+            .get_root_declaration(reference, true)
     }
 
     #[allow(dead_code)]
@@ -91,7 +108,7 @@ impl DeclarationTable {
                     last_reference = temp;
                 }
 
-                match scope.symbols.get(&last_reference) {
+                match scope.get(&last_reference, false) {
                     Some(decl) => declaration = Some(decl.clone()),
                     None => {
                         if let Some(parent) = &scope.parent {
@@ -109,12 +126,17 @@ impl DeclarationTable {
     }
 
     fn insert(&mut self, name: String, value: Declaration) -> () {
-        self.current_scope.borrow_mut().symbols.insert(name, value);
+        let mut scope = self.current_scope.borrow_mut();
+        match value {
+            Declaration::Value { .. } => scope.values.insert(name, value),
+            _ => scope.symbols.insert(name, value),
+        };
     }
 
     fn add_child_scope(&mut self) -> &mut DeclarationTable {
         let child_scope = Rc::new(RefCell::new(Scope {
             symbols: BTreeMap::new(),
+            values: BTreeMap::new(),
             children: None,
             parent: Some(Rc::clone(&self.current_scope)),
         }));
@@ -144,7 +166,7 @@ impl DeclarationTable {
         self
     }
 
-    fn get_root_declaration(&self, reference: &str) -> Option<Declaration> {
+    fn get_root_declaration(&self, reference: &str, is_value: bool) -> Option<Declaration> {
         let mut declaration: Option<Declaration> = None;
         let mut queue = VecDeque::from([Rc::clone(&self.current_scope)]);
         let mut references = vec![reference.to_string()];
@@ -164,7 +186,7 @@ impl DeclarationTable {
                     last_reference = temp;
                 }
 
-                match scope.symbols.get(&last_reference) {
+                match scope.get(&last_reference, is_value) {
                     Some(decl) => declaration = Some(decl.clone()),
                     None => {
                         if let Some(parent) = &scope.parent {
@@ -218,6 +240,7 @@ impl DeclarationTable {
 pub enum Declaration {
     Alias { to: String },
     Type { node: Rc<SchemyNode<'static>> },
+    Value { node: Rc<SchemyNode<'static>> },
     Export { name: String, source_file_name: String },
     Import { name: String, source_file_name: String },
 }
@@ -227,6 +250,7 @@ impl Clone for Declaration {
         match self {
             Self::Alias { to } => Self::Alias { to: to.clone() },
             Self::Type { node } => Self::Type { node: node.clone() },
+            Self::Value { node } => Self::Value { node: node.clone() },
             Self::Export { name, source_file_name } => Self::Export {
                 name: name.clone(),
                 source_file_name: source_file_name.clone(),
@@ -10,6 +10,7 @@ pub struct DeferredSchemas {
     local_types: BTreeMap<String, Vec<LocalType>>,
     modules: Vec<String>,
     operation_types: BTreeMap<String, BTreeMap<String, OperationType>>,
+    param_groups: BTreeMap<String, Vec<ParamGroupType>>,
 }
 
 impl Store {
@@ -24,6 +25,10 @@ impl Store {
         type_name: &str,
         node: Rc<SchemyNode<'static>>,
     ) -> () {
+        if !self.deferred_schemas.modules.contains(&file_path.to_string()) {
+            self.deferred_schemas.modules.push(file_path.to_string());
+        }
+
         self.deferred_schemas
             .local_types
             .entry(file_path.to_string())
@@ -78,6 +83,28 @@ impl Store {
         );
     }
 
+    pub(crate) fn defer_param_group(
+        &mut self,
+        source_file_name: &str,
+        operation: &Rc<RefCell<ApiPathOperation>>,
+        type_name: &str,
+        location: &str,
+    ) -> () {
+        if !self.deferred_schemas.modules.contains(&source_file_name.to_string()) {
+            self.deferred_schemas.modules.push(source_file_name.to_string());
+        }
+
+        self.deferred_schemas
+            .param_groups
+            .entry(source_file_name.to_string())
+            .or_insert(Vec::new())
+            .push(ParamGroupType {
+                location: location.to_string(),
+                operation: operation.clone(),
+                type_name: type_name.to_string(),
+            });
+    }
+
     pub(crate) fn recognize_external_type(&mut self, name: &str, source_file_name: &str) -> Option<ExternalType> {
         match self.deferred_schemas.external_types.get_mut(source_file_name) {
             Some(types) => match types.get_mut(name) {
@@ -98,6 +125,18 @@ impl Store {
         }
     }
 
+    pub fn recognize_param_groups(&mut self, type_name: &str, source_file_name: &str) -> Vec<ParamGroupType> {
+        match self.deferred_schemas.param_groups.get_mut(source_file_name) {
+            Some(groups) => {
+                let (recognized, remaining): (Vec<ParamGroupType>, Vec<ParamGroupType>) =
+                    groups.drain(..).partition(|group| group.type_name.eq(type_name));
+                groups.extend(remaining);
+                recognized
+            }
+            None => Vec::new(),
+        }
+    }
+
     pub fn recognize_local_types(&mut self, file_path: &str) -> Vec<LocalType> {
         if let Some(local_types) = self.deferred_schemas.local_types.get_mut(file_path) {
             local_types.drain(..).collect()
@@ -130,6 +169,13 @@ pub struct OperationType {
     pub type_name: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct ParamGroupType {
+    pub location: String,
+    pub operation: Rc<RefCell<ApiPathOperation>>,
+    pub type_name: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct LocalType {
     pub schema_name: String,
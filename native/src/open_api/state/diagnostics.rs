@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use swc_common::Span;
+
+use crate::typescript::SchemyNode;
+
+use super::Store;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub file_path: Option<String>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+}
+
+impl Store {
+    pub fn add_diagnostic(
+        &mut self,
+        severity: Severity,
+        message: &str,
+        file_path: &str,
+        root: Option<&Rc<SchemyNode<'static>>>,
+    ) -> () {
+        let span = root.and_then(|root| root.span());
+        self.add_diagnostic_at(severity, message, file_path, span);
+    }
+
+    pub fn take_diagnostics(&mut self) -> Vec<Diagnostic> {
+        for error in self.modules.take_errors() {
+            self.add_diagnostic_at(Severity::Error, &error.message, &error.file_path, error.span);
+        }
+
+        self.diagnostics.drain(..).collect()
+    }
+
+    pub(crate) fn add_diagnostic_at(
+        &mut self,
+        severity: Severity,
+        message: &str,
+        file_path: &str,
+        span: Option<Span>,
+    ) -> () {
+        let (line, column) = match span {
+            Some(span) if !span.is_dummy() => {
+                let (line, column) = self.modules.location(span.lo);
+                (Some(line), Some(column))
+            }
+            _ => (None, None),
+        };
+
+        self.diagnostics.push(Diagnostic {
+            severity,
+            message: message.to_string(),
+            file_path: Some(file_path.to_string()),
+            line,
+            column,
+        });
+    }
+}
@@ -0,0 +1,40 @@
+use swc_common::{
+    comments::{CommentKind, Comments, SingleThreadedComments},
+    BytePos,
+};
+
+use super::Store;
+
+impl Store {
+    pub(crate) fn add_comments(&mut self, file_path: &str, comments: SingleThreadedComments) -> () {
+        self.comments.insert(file_path.to_string(), comments);
+    }
+
+    pub(crate) fn get_description(&self, file_path: &str, pos: BytePos) -> Option<String> {
+        let comments = self.comments.get(file_path)?;
+        let leading = comments.get_leading(pos)?;
+
+        leading
+            .iter()
+            .rev()
+            .find(|comment| matches!(comment.kind, CommentKind::Block) && comment.text.starts_with('*'))
+            .map(|comment| get_jsdoc_description(&comment.text))
+            .filter(|description| !description.is_empty())
+    }
+}
+
+fn get_jsdoc_description(text: &str) -> String {
+    let mut lines = vec![];
+    for line in text.lines() {
+        let line = line.trim().trim_start_matches('*').trim();
+        if line.starts_with('@') {
+            break;
+        }
+
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+
+    lines.join("\n")
+}
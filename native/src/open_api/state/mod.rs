@@ -1,14 +1,43 @@
 mod caching;
 mod declaration_table;
 mod deferred;
+mod diagnostics;
+mod documentation;
+mod naming;
+mod references;
+mod type_arguments;
 
-use self::{declaration_table::DeclarationTables, deferred::DeferredSchemas};
+use std::collections::BTreeMap;
 
-pub use self::declaration_table::Declaration;
+use swc_common::comments::SingleThreadedComments;
+
+use crate::typescript::ModuleCache;
+
+use self::{
+    declaration_table::DeclarationTables, deferred::DeferredSchemas, naming::SchemaNames, type_arguments::TypeArguments,
+};
+
+use super::schema::OpenApiOptions;
+
+pub(crate) use self::caching::get_commonjs_export_names;
+
+pub use self::{
+    declaration_table::Declaration,
+    diagnostics::{Diagnostic, Severity},
+    references::SchemaReference,
+    type_arguments::TypeArgument,
+};
 
 pub struct Store {
+    comments: BTreeMap<String, SingleThreadedComments>,
     deferred_schemas: DeferredSchemas,
+    diagnostics: Vec<Diagnostic>,
+    modules: ModuleCache,
+    options: OpenApiOptions,
+    references: Vec<SchemaReference>,
+    schema_names: SchemaNames,
     symbol_tables: DeclarationTables,
+    type_arguments: TypeArguments,
 }
 
 impl Store {
@@ -16,14 +45,29 @@ impl Store {
         self.symbol_tables.get_root_declaration(file_path, reference)
     }
 
+    pub fn get_root_value_declaration(&mut self, file_path: &str, reference: &str) -> Option<Declaration> {
+        self.symbol_tables.get_root_value_declaration(file_path, reference)
+    }
+
     pub fn get_root_declaration_name(&mut self, file_path: &str, reference: &str) -> String {
         self.symbol_tables.get_root_declaration_name(file_path, reference)
     }
 
-    pub(crate) fn new() -> Self {
+    pub fn options(&self) -> &OpenApiOptions {
+        &self.options
+    }
+
+    pub(crate) fn new(options: OpenApiOptions) -> Self {
         Self {
+            comments: BTreeMap::new(),
             deferred_schemas: DeferredSchemas::default(),
+            diagnostics: Vec::new(),
+            modules: ModuleCache::new(options.compiler_options.clone()),
+            options,
+            references: Vec::new(),
+            schema_names: SchemaNames::default(),
             symbol_tables: DeclarationTables::default(),
+            type_arguments: TypeArguments::default(),
         }
     }
 }
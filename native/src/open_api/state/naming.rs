@@ -0,0 +1,118 @@
+use std::{collections::BTreeMap, path::Path};
+
+use crate::open_api::schema::SchemaNameCollision;
+
+use super::{Declaration, Store};
+
+#[derive(Default)]
+pub struct SchemaNames {
+    collisions: Vec<String>,
+    names: BTreeMap<String, String>,
+    owners: BTreeMap<String, String>,
+}
+
+impl Store {
+    pub fn get_schema_name(&mut self, file_path: &str, type_name: &str) -> String {
+        let (module_file_name, declared_name) = self.find_declaring_module(file_path, type_name);
+        let key = format!("{}#{}", module_file_name, declared_name);
+        if let Some(schema_name) = self.schema_names.names.get(&key) {
+            return schema_name.clone();
+        }
+
+        let base_name = match declared_name.as_str() {
+            "default" => type_name.to_string(),
+            _ => declared_name,
+        };
+
+        let mut schema_name = base_name.clone();
+        if let Some(owner) = self.schema_names.owners.get(&schema_name) {
+            match self.options.schema_name_collision {
+                SchemaNameCollision::Error => {
+                    let owner_file_name = owner.rsplit_once('#').map(|(file, _)| file).unwrap_or_default();
+                    let collision = format!(
+                        "Schema name '{}' is declared in both '{}' and '{}'",
+                        base_name, owner_file_name, module_file_name
+                    );
+
+                    self.schema_names.collisions.push(collision);
+                    self.schema_names.names.insert(key, schema_name.clone());
+                    return schema_name;
+                }
+                SchemaNameCollision::Prefix => {
+                    schema_name = format!("{}{}", get_module_prefix(&module_file_name), base_name);
+                }
+                SchemaNameCollision::Suffix => {}
+            }
+
+            let candidate = schema_name.clone();
+            let mut index = 1;
+            while self.schema_names.owners.contains_key(&schema_name) {
+                index += 1;
+                schema_name = format!("{}{}", candidate, index);
+            }
+        }
+
+        self.schema_names.owners.insert(schema_name.clone(), key.clone());
+        self.schema_names.names.insert(key, schema_name.clone());
+        schema_name
+    }
+
+    pub fn schema_name_collisions(&self) -> &Vec<String> {
+        &self.schema_names.collisions
+    }
+
+    fn find_declaring_module(&mut self, file_path: &str, type_name: &str) -> (String, String) {
+        let mut module_file_name = file_path.to_string();
+        let mut declared_name = type_name.to_string();
+        let mut visited = vec![];
+
+        loop {
+            match self.get_root_declaration(&module_file_name, &declared_name) {
+                Some(Declaration::Import { name, source_file_name })
+                | Some(Declaration::Export { name, source_file_name }) => {
+                    let reference = format!("{}#{}", source_file_name, name);
+                    if visited.contains(&reference) {
+                        return (source_file_name, name);
+                    }
+
+                    visited.push(reference);
+                    self.parse_module(&source_file_name);
+                    module_file_name = source_file_name;
+                    declared_name = name;
+                }
+                Some(_) => return (module_file_name, declared_name),
+                None => return (String::new(), declared_name),
+            }
+        }
+    }
+}
+
+fn get_module_prefix(file_path: &str) -> String {
+    let path = Path::new(file_path);
+    let mut segments = vec![];
+    if let Some(directory) = path.parent().and_then(|parent| parent.file_name()) {
+        segments.push(directory.to_string_lossy().to_string());
+    }
+
+    if let Some(stem) = path.file_stem() {
+        let stem = stem.to_string_lossy();
+        let stem = stem.strip_suffix(".d").unwrap_or(&stem).to_string();
+        if !stem.eq("index") {
+            segments.push(stem);
+        }
+    }
+
+    let mut prefix = String::new();
+    for word in segments
+        .iter()
+        .flat_map(|segment| segment.split(|c: char| !c.is_alphanumeric()))
+    {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            prefix.push(first.to_ascii_uppercase());
+            prefix.extend(chars);
+        }
+    }
+
+    prefix
+}
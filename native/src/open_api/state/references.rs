@@ -0,0 +1,38 @@
+use std::rc::Rc;
+
+use swc_common::Span;
+
+use crate::typescript::SchemyNode;
+
+use super::Store;
+
+#[derive(Clone, Debug)]
+pub struct SchemaReference {
+    pub schema_name: String,
+    pub file_path: String,
+    pub span: Option<Span>,
+}
+
+impl Store {
+    pub fn track_reference(&mut self, schema_name: &str, file_path: &str, root: &Rc<SchemyNode<'static>>) -> () {
+        self.references.push(SchemaReference {
+            schema_name: schema_name.to_string(),
+            file_path: file_path.to_string(),
+            span: root.span(),
+        });
+    }
+
+    pub fn references(&self) -> &Vec<SchemaReference> {
+        &self.references
+    }
+
+    pub fn reference_location(&self, reference: &SchemaReference) -> String {
+        match reference.span {
+            Some(span) if !span.is_dummy() => {
+                let (line, column) = self.modules.location(span.lo);
+                format!("{}:{}:{}", reference.file_path, line, column)
+            }
+            _ => reference.file_path.clone(),
+        }
+    }
+}
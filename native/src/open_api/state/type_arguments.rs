@@ -0,0 +1,101 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use swc_common::{Span, DUMMY_SP};
+use swc_ecma_ast::{Str, TsLit, TsLitType, TsType};
+
+use crate::typescript::SchemyNode;
+
+use super::Store;
+
+// generic aliases nest at most this deep before evaluation gives up
+const MAX_TYPE_ARGUMENT_SCOPES: usize = 32;
+
+#[derive(Clone, Debug)]
+pub enum TypeArgument {
+    Key(String),
+    Type {
+        node: Rc<SchemyNode<'static>>,
+        file_path: String,
+    },
+}
+
+pub struct TypeArgumentScope {
+    id: usize,
+    arguments: BTreeMap<String, TypeArgument>,
+}
+
+#[derive(Default)]
+pub struct TypeArguments {
+    scopes: Vec<TypeArgumentScope>,
+    scope_count: usize,
+    evaluations: Vec<(Span, usize)>,
+    key_types: BTreeMap<String, &'static TsType>,
+}
+
+impl Store {
+    pub(crate) fn bind_type_arguments(&mut self, arguments: BTreeMap<String, TypeArgument>) -> bool {
+        if self.type_arguments.scopes.len() >= MAX_TYPE_ARGUMENT_SCOPES {
+            return false;
+        }
+
+        self.type_arguments.scope_count += 1;
+        self.type_arguments.scopes.push(TypeArgumentScope {
+            id: self.type_arguments.scope_count,
+            arguments,
+        });
+        true
+    }
+
+    pub(crate) fn unbind_type_arguments(&mut self) -> () {
+        self.type_arguments.scopes.pop();
+    }
+
+    // the innermost binding of a type parameter, along with the depth of the scope that bound it
+    pub(crate) fn get_type_argument(&self, name: &str) -> Option<(TypeArgument, usize)> {
+        self.type_arguments
+            .scopes
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(depth, scope)| scope.arguments.get(name).map(|argument| (argument.clone(), depth)))
+    }
+
+    // hides the scopes at and above depth, so an argument is evaluated where it was written
+    pub(crate) fn suspend_type_arguments(&mut self, depth: usize) -> Vec<TypeArgumentScope> {
+        self.type_arguments.scopes.split_off(depth)
+    }
+
+    pub(crate) fn resume_type_arguments(&mut self, scopes: Vec<TypeArgumentScope>) -> () {
+        self.type_arguments.scopes.extend(scopes);
+    }
+
+    // marks the node at span as being evaluated under the current type arguments, returning false when it
+    // already is, which means the node refers back to itself
+    pub(crate) fn begin_evaluation(&mut self, span: Span) -> bool {
+        let scope = self.type_arguments.scopes.last().map_or(0, |scope| scope.id);
+        if !span.is_dummy() && self.type_arguments.evaluations.contains(&(span, scope)) {
+            return false;
+        }
+
+        self.type_arguments.evaluations.push((span, scope));
+        true
+    }
+
+    pub(crate) fn end_evaluation(&mut self) -> () {
+        self.type_arguments.evaluations.pop();
+    }
+
+    // the string literal type of a key, which lives as long as the parsed modules do
+    pub(crate) fn get_key_type(&mut self, key: &str) -> &'static TsType {
+        self.type_arguments.key_types.entry(key.to_string()).or_insert_with(|| {
+            Box::leak(Box::new(TsType::TsLitType(TsLitType {
+                span: DUMMY_SP,
+                lit: TsLit::Str(Str {
+                    span: DUMMY_SP,
+                    value: key.into(),
+                    raw: None,
+                }),
+            })))
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+
+use jsonschema::{Draft, JSONSchema};
+use serde_json::Value;
+
+// the published OpenApi 3.1 schema, https://spec.openapis.org/oas/3.1/schema/2022-10-07, vendored unchanged
+const OPEN_API_3_1_SCHEMA: &str = include_str!("openapi-3.1.schema.json");
+
+const METHODS: [&str; 8] = ["get", "put", "post", "delete", "options", "head", "patch", "trace"];
+
+pub fn validate_schema(schema: &Value, check_meta_schema: bool) -> Vec<String> {
+    let mut errors = vec![];
+    if check_meta_schema {
+        errors.extend(validate_meta_schema(schema));
+    }
+
+    find_unresolved_references(schema, schema, "", &mut errors);
+    find_duplicate_operation_ids(schema, &mut errors);
+
+    errors
+}
+
+fn validate_meta_schema(schema: &Value) -> Vec<String> {
+    let meta_schema: Value =
+        serde_json::from_str(OPEN_API_3_1_SCHEMA).expect("Could not deserialize the OpenApi meta-schema");
+    let validator = JSONSchema::options()
+        .with_draft(Draft::Draft202012)
+        .compile(&meta_schema)
+        .expect("Could not compile the OpenApi meta-schema");
+
+    let errors = match validator.validate(schema) {
+        Ok(_) => vec![],
+        Err(errors) => errors
+            .map(|error| format!("#{}: {}", error.instance_path, error))
+            .collect(),
+    };
+
+    errors
+}
+
+fn find_unresolved_references(document: &Value, value: &Value, location: &str, errors: &mut Vec<String>) -> () {
+    match value {
+        Value::Object(object) => {
+            for (key, child) in object {
+                match (key.as_str(), child) {
+                    ("$ref", Value::String(reference)) => {
+                        if let Some(pointer) = reference.strip_prefix('#') {
+                            if document.pointer(pointer).is_none() {
+                                errors.push(format!("#{}: Unresolved reference '{}'", location, reference));
+                            }
+                        }
+                    }
+                    _ => {
+                        let location = format!("{}/{}", location, escape_pointer(key));
+                        find_unresolved_references(document, child, &location, errors);
+                    }
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                let location = format!("{}/{}", location, index);
+                find_unresolved_references(document, item, &location, errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn find_duplicate_operation_ids(document: &Value, errors: &mut Vec<String>) -> () {
+    let mut operation_ids: BTreeMap<&str, String> = BTreeMap::new();
+    if let Some(paths) = document.get("paths").and_then(|paths| paths.as_object()) {
+        for (path, path_item) in paths {
+            for method in METHODS {
+                let operation_id = path_item
+                    .get(method)
+                    .and_then(|operation| operation.get("operationId"))
+                    .and_then(|operation_id| operation_id.as_str());
+
+                if let Some(operation_id) = operation_id {
+                    let location = format!("#/paths/{}/{}", escape_pointer(path), method);
+                    match operation_ids.get(operation_id) {
+                        Some(first_location) => errors.push(format!(
+                            "{}: Duplicate operationId '{}' is also used at {}",
+                            location, operation_id, first_location
+                        )),
+                        None => {
+                            operation_ids.insert(operation_id, location);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
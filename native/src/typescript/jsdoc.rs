@@ -0,0 +1,692 @@
+use std::{collections::BTreeSet, rc::Rc};
+
+use swc_common::{
+    comments::{Comment, CommentKind, Comments, SingleThreadedComments},
+    BytePos, SourceFile, Span, Spanned,
+};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+
+use super::{NodeKind, SchemyNode};
+
+// Rewrites a javascript module as typescript, so the rest of the pipeline can read its JSDoc types.
+// Annotations are inserted inline and declarations are appended, which keeps the original line numbers.
+pub fn annotate_jsdoc(fm: &SourceFile, module: Module, comments: &SingleThreadedComments) -> String {
+    let mut annotator = Annotator {
+        comments,
+        declarations: vec![],
+        fm,
+        imports: vec![],
+        insertions: vec![],
+        locals: get_module_names(&module),
+        visited: BTreeSet::new(),
+    };
+
+    annotator.add_typedefs();
+    annotator.visit(&SchemyNode::from_module(module));
+    annotator.finish()
+}
+
+struct Annotator<'a> {
+    comments: &'a SingleThreadedComments,
+    declarations: Vec<String>,
+    fm: &'a SourceFile,
+    imports: Vec<(String, String, String)>,
+    insertions: Vec<(BytePos, String)>,
+    locals: BTreeSet<String>,
+    visited: BTreeSet<BytePos>,
+}
+
+#[derive(Debug)]
+struct JsDocTag {
+    tag: String,
+    type_expr: Option<String>,
+    name: Option<String>,
+    description: String,
+}
+
+#[derive(Debug)]
+struct JsDocProperty {
+    path: Vec<String>,
+    type_expr: Option<String>,
+    optional: bool,
+    description: String,
+}
+
+impl<'a> Annotator<'a> {
+    fn finish(mut self) -> String {
+        let fm = self.fm;
+        let source = fm.src.as_str();
+        let mut annotated = String::with_capacity(source.len());
+        let mut offset = 0;
+
+        self.insertions.sort_by_key(|(pos, _)| *pos);
+        for (pos, text) in &self.insertions {
+            let index = (*pos - fm.start_pos).0 as usize;
+            annotated.push_str(&source[offset..index]);
+            annotated.push_str(text);
+            offset = index;
+        }
+
+        annotated.push_str(&source[offset..]);
+        annotated.push('\n');
+
+        for (name, local, specifier) in &self.imports {
+            match name.eq(local) {
+                true => annotated.push_str(&format!("import {{ {} }} from '{}';\n", name, specifier)),
+                false => annotated.push_str(&format!("import {{ {} as {} }} from '{}';\n", name, local, specifier)),
+            }
+        }
+
+        for declaration in &self.declarations {
+            annotated.push_str(declaration);
+            annotated.push('\n');
+        }
+
+        annotated
+    }
+
+    fn visit(&mut self, root: &Rc<SchemyNode<'static>>) {
+        match root.kind {
+            NodeKind::ArrowExpr(raw) if self.visited.insert(raw.span.lo) => self.annotate_arrow(root, raw),
+            NodeKind::FnDecl(raw) if self.visited.insert(raw.function.span.lo) => {
+                self.annotate_function(root, &raw.function)
+            }
+            NodeKind::FnExpr(raw) if self.visited.insert(raw.function.span.lo) => {
+                self.annotate_function(root, &raw.function)
+            }
+            NodeKind::VarDeclarator(raw) if self.visited.insert(raw.span.lo) => self.annotate_variable(root, raw),
+            _ => {}
+        }
+
+        for child in root.children() {
+            self.visit(&child);
+        }
+    }
+
+    fn add_typedefs(&mut self) {
+        let mut comments: Vec<Comment> = vec![];
+        let (leading, trailing) = self.comments.borrow_all();
+        for values in leading.values().chain(trailing.values()) {
+            comments.extend(values.iter().filter(|comment| is_jsdoc(comment)).cloned());
+        }
+
+        drop(leading);
+        drop(trailing);
+
+        comments.sort_by_key(|comment| comment.span.lo);
+        for comment in &comments {
+            for tag in get_tags(&comment.text) {
+                if let ("typedef", Some(name)) = (tag.tag.as_str(), tag.name) {
+                    self.locals.insert(name);
+                }
+            }
+        }
+
+        for comment in comments {
+            let mut typedef: Option<(JsDocTag, Vec<JsDocProperty>)> = None;
+            for tag in get_tags(&comment.text) {
+                match tag.tag.as_str() {
+                    "typedef" => {
+                        if let Some((typedef, properties)) = typedef.take() {
+                            self.add_typedef(typedef, properties);
+                        }
+
+                        typedef = Some((tag, vec![]));
+                    }
+                    "property" | "prop" => {
+                        if let Some((_, properties)) = &mut typedef {
+                            properties.extend(get_property(&tag));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some((typedef, properties)) = typedef {
+                self.add_typedef(typedef, properties);
+            }
+        }
+    }
+
+    fn add_typedef(&mut self, typedef: JsDocTag, properties: Vec<JsDocProperty>) {
+        let name = match &typedef.name {
+            Some(name) => name.clone(),
+            None => return,
+        };
+
+        let comment = get_comment(&typedef.description);
+        let properties: Vec<&JsDocProperty> = properties.iter().collect();
+        let declaration = match properties.is_empty() {
+            true => {
+                let type_expr = self.get_type(typedef.type_expr.as_deref().unwrap_or("*"));
+                format!("{}export type {} = {};", comment, name, type_expr)
+            }
+            false => format!(
+                "{}export interface {} {}",
+                comment,
+                name,
+                self.get_object_type(&properties, 0)
+            ),
+        };
+
+        self.declarations.push(declaration);
+    }
+
+    fn annotate_arrow(&mut self, root: &Rc<SchemyNode<'static>>, arrow: &ArrowExpr) {
+        let tags = self.get_jsdoc(root);
+        if tags.is_empty() {
+            return;
+        }
+
+        let params = get_params(&tags);
+        let fm = self.fm;
+        let source = fm.src.as_str();
+        for (index, param) in arrow.params.iter().enumerate() {
+            let is_bare = arrow.params.len() == 1 && {
+                let start = (param.span().lo - fm.start_pos).0 as usize;
+                !source[..start].trim_end().ends_with('(')
+            };
+
+            if self.annotate_pattern(param, index, &params) && is_bare {
+                self.insertions.push((param.span().lo, "(".into()));
+                self.insertions.push((param.span().hi, ")".into()));
+            }
+        }
+
+        if arrow.return_type.is_none() {
+            if let Some(returns) = get_returns(&tags) {
+                let start = arrow
+                    .params
+                    .last()
+                    .map(|param| param.span().hi)
+                    .unwrap_or(arrow.span.lo);
+                let from = (start - fm.start_pos).0 as usize;
+                let to = (arrow.body.span().lo - fm.start_pos).0 as usize;
+                if let Some(arrow_index) = source[from..to].find("=>") {
+                    let type_expr = self.get_type(&returns);
+                    let pos = start + BytePos(arrow_index as u32);
+                    self.insertions.push((pos, format!(": {} ", type_expr)));
+                }
+            }
+        }
+    }
+
+    fn annotate_function(&mut self, root: &Rc<SchemyNode<'static>>, function: &Function) {
+        let tags = self.get_jsdoc(root);
+        if tags.is_empty() {
+            return;
+        }
+
+        let params = get_params(&tags);
+        for (index, param) in function.params.iter().enumerate() {
+            self.annotate_pattern(&param.pat, index, &params);
+        }
+
+        if let (None, Some(body)) = (&function.return_type, &function.body) {
+            if let Some(returns) = get_returns(&tags) {
+                let type_expr = self.get_type(&returns);
+                self.insertions.push((body.span.lo, format!(": {} ", type_expr)));
+            }
+        }
+    }
+
+    fn annotate_variable(&mut self, root: &Rc<SchemyNode<'static>>, declarator: &VarDeclarator) {
+        let tags = self.get_jsdoc(root);
+        let name = match &declarator.name {
+            Pat::Ident(identifier) => identifier,
+            _ => return,
+        };
+
+        for tag in &tags {
+            match (tag.tag.as_str(), &tag.type_expr) {
+                ("type", Some(type_expr)) if name.type_ann.is_none() => {
+                    let type_expr = self.get_type(type_expr);
+                    self.insertions.push((name.id.span.hi, format!(": {}", type_expr)));
+                }
+                ("enum", _) => match declarator.init.as_deref() {
+                    Some(Expr::Object(object)) => self.add_enum(&name.id.sym, object),
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+
+    fn annotate_pattern(&mut self, pattern: &Pat, index: usize, params: &[JsDocProperty]) -> bool {
+        let (name, pos, can_be_optional) = match pattern {
+            Pat::Ident(identifier) if identifier.type_ann.is_none() => {
+                (Some(identifier.id.sym.to_string()), identifier.id.span.hi, true)
+            }
+            Pat::Assign(assign) => match &*assign.left {
+                Pat::Ident(identifier) if identifier.type_ann.is_none() => {
+                    (Some(identifier.id.sym.to_string()), identifier.id.span.hi, false)
+                }
+                _ => return false,
+            },
+            Pat::Object(object) if object.type_ann.is_none() => (None, object.span.hi, false),
+            Pat::Array(array) if array.type_ann.is_none() => (None, array.span.hi, false),
+            _ => return false,
+        };
+
+        // destructured parameters are matched by position, like typescript does
+        let top_level: Vec<&JsDocProperty> = params.iter().filter(|param| param.path.len() == 1).collect();
+        let param = match &name {
+            Some(name) => top_level.iter().find(|param| param.path[0].eq(name)),
+            None => top_level.get(index),
+        };
+
+        let param = match param {
+            Some(param) => *param,
+            None => return false,
+        };
+
+        let nested: Vec<&JsDocProperty> = params
+            .iter()
+            .filter(|nested| nested.path.len() > 1 && nested.path[0].eq(&param.path[0]))
+            .collect();
+        let type_expr = match nested.is_empty() {
+            true => self.get_type(param.type_expr.as_deref().unwrap_or("*")),
+            false => self.get_object_type(&nested, 1),
+        };
+
+        let optional = match param.optional && can_be_optional {
+            true => "?",
+            false => "",
+        };
+
+        self.insertions.push((pos, format!("{}: {}", optional, type_expr)));
+        true
+    }
+
+    fn add_enum(&mut self, name: &str, object: &ObjectLit) {
+        let fm = self.fm;
+        let source = fm.src.as_str();
+        let mut members = vec![];
+        for prop in &object.props {
+            if let PropOrSpread::Prop(prop) = prop {
+                if let Prop::KeyValue(key_value) = &**prop {
+                    match &*key_value.value {
+                        Expr::Lit(Lit::Str(_)) | Expr::Lit(Lit::Num(_)) => {
+                            let span = key_value.value.span();
+                            let from = (span.lo - fm.start_pos).0 as usize;
+                            let to = (span.hi - fm.start_pos).0 as usize;
+                            members.push(source[from..to].to_string());
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // a type shares the name of the const without redeclaring it
+        if !members.is_empty() {
+            self.declarations
+                .push(format!("export type {} = {};", name, members.join(" | ")));
+        }
+    }
+
+    fn get_jsdoc(&self, root: &Rc<SchemyNode<'static>>) -> Vec<JsDocTag> {
+        let mut current = Some(root.clone());
+        while let Some(node) = current {
+            let span = match get_span(&node.kind) {
+                Some(span) => span,
+                None => return vec![],
+            };
+
+            let comment = self
+                .comments
+                .get_leading(span.lo)
+                .and_then(|comments| comments.into_iter().rev().find(is_jsdoc));
+
+            if let Some(comment) = comment {
+                return get_tags(&comment.text);
+            }
+
+            current = node.parent();
+        }
+
+        vec![]
+    }
+
+    fn get_object_type(&mut self, properties: &[&JsDocProperty], depth: usize) -> String {
+        let mut members = vec![];
+        for property in properties.iter().filter(|property| property.path.len() == depth + 1) {
+            let name = &property.path[depth];
+            let nested: Vec<&JsDocProperty> = properties
+                .iter()
+                .filter(|nested| nested.path.len() > depth + 1 && nested.path[..depth + 1] == property.path[..])
+                .cloned()
+                .collect();
+
+            let type_expr = match nested.is_empty() {
+                true => self.get_type(property.type_expr.as_deref().unwrap_or("*")),
+                false => self.get_object_type(&nested, depth + 1),
+            };
+
+            let optional = match property.optional {
+                true => "?",
+                false => "",
+            };
+
+            members.push(format!(
+                "{}{}{}: {};",
+                get_comment(&property.description),
+                name,
+                optional,
+                type_expr
+            ));
+        }
+
+        format!("{{ {} }}", members.join(" "))
+    }
+
+    // imported names are aliased when they would clash with a name declared in the module or
+    // with a name imported from another module
+    fn get_import_local(&mut self, name: String, specifier: String) -> String {
+        let existing = self
+            .imports
+            .iter()
+            .find(|(imported, _, from)| imported.eq(&name) && from.eq(&specifier));
+        if let Some((_, local, _)) = existing {
+            return local.clone();
+        }
+
+        let mut local = name.clone();
+        let mut index = 1;
+        while self.locals.contains(&local) || self.imports.iter().any(|(_, imported, _)| imported.eq(&local)) {
+            local = format!("__jsdoc_{}{}", name, index);
+            index += 1;
+        }
+
+        self.imports.push((name, local.clone(), specifier));
+        local
+    }
+
+    fn get_type(&mut self, type_expr: &str) -> String {
+        let mut type_expr = type_expr.split_whitespace().collect::<Vec<&str>>().join(" ");
+
+        while let Some(start) = type_expr.find("import(") {
+            match get_import_type(&type_expr[start..]) {
+                Some((specifier, name, length)) => {
+                    let local = self.get_import_local(name, specifier);
+                    type_expr.replace_range(start..start + length, &local);
+                }
+                None => break,
+            }
+        }
+
+        let mut type_expr = type_expr.replace(".<", "<").replace('*', "any");
+        type_expr = replace_word(&type_expr, "Object<", "Record<");
+        type_expr = replace_word(&type_expr, "Object", "any");
+
+        if let Some(nullable) = type_expr.strip_prefix('?') {
+            type_expr = format!("{} | null", nullable);
+        } else if let Some(non_nullable) = type_expr.strip_prefix('!') {
+            type_expr = non_nullable.to_string();
+        }
+
+        // closure style types like function(string): number have no typescript equivalent
+        match is_valid_type(&type_expr) {
+            true => type_expr,
+            false => "any".into(),
+        }
+    }
+}
+
+fn get_module_names(module: &Module) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    for item in &module.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                for specifier in &import.specifiers {
+                    let local = match specifier {
+                        ImportSpecifier::Named(named) => &named.local,
+                        ImportSpecifier::Default(default) => &default.local,
+                        ImportSpecifier::Namespace(namespace) => &namespace.local,
+                    };
+                    names.insert(local.sym.to_string());
+                }
+                continue;
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(export)) => &export.decl,
+            ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
+            _ => continue,
+        };
+
+        match decl {
+            Decl::Class(class) => {
+                names.insert(class.ident.sym.to_string());
+            }
+            Decl::Fn(function) => {
+                names.insert(function.ident.sym.to_string());
+            }
+            Decl::Var(var) => {
+                for declarator in &var.decls {
+                    if let Pat::Ident(identifier) = &declarator.name {
+                        names.insert(identifier.id.sym.to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    names
+}
+
+fn is_jsdoc(comment: &Comment) -> bool {
+    matches!(comment.kind, CommentKind::Block) && comment.text.starts_with('*')
+}
+
+fn get_span(kind: &NodeKind) -> Option<Span> {
+    match kind {
+        NodeKind::ArrowExpr(raw) => Some(raw.span),
+        NodeKind::Decl(raw) => Some(raw.span()),
+        NodeKind::ExportDecl(raw) => Some(raw.span),
+        NodeKind::ExportDefaultExpr(raw) => Some(raw.span),
+        NodeKind::Expr(raw) => Some(raw.span()),
+        NodeKind::FnDecl(raw) => Some(raw.function.span),
+        NodeKind::FnExpr(raw) => Some(raw.function.span),
+        NodeKind::ModuleItem(raw) => Some(raw.span()),
+        NodeKind::VarDecl(raw) => Some(raw.span),
+        NodeKind::VarDeclarator(raw) => Some(raw.span),
+        _ => None,
+    }
+}
+
+fn get_comment(description: &str) -> String {
+    match description.is_empty() {
+        true => String::new(),
+        false => format!("/** {} */ ", description.replace("*/", "* /")),
+    }
+}
+
+fn get_tags(text: &str) -> Vec<JsDocTag> {
+    let text = text
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .collect::<Vec<&str>>()
+        .join("\n");
+
+    // tags start at the beginning of a line
+    let mut tags = vec![];
+    let mut rest = match text.starts_with('@') {
+        true => text.as_str(),
+        false => match text.find("\n@") {
+            Some(index) => &text[index + 1..],
+            None => "",
+        },
+    };
+
+    while let Some(tag_text) = rest.strip_prefix('@') {
+        let tag_length = tag_text.find(|c: char| !c.is_alphanumeric()).unwrap_or(tag_text.len());
+        let tag = tag_text[..tag_length].to_string();
+        let mut body = tag_text[tag_length..].trim_start();
+
+        let type_expr = match body.starts_with('{') {
+            true => match get_braced(body) {
+                Some(length) => {
+                    let type_expr = body[1..length - 1].trim().to_string();
+                    body = body[length..].trim_start();
+                    Some(type_expr)
+                }
+                None => None,
+            },
+            false => None,
+        };
+
+        let end = body.find("\n@").map(|index| index + 1).unwrap_or(body.len());
+        let (content, remaining) = body.split_at(end);
+        let content = content.trim();
+
+        let (name, description) = match tag.as_str() {
+            "param" | "arg" | "argument" | "property" | "prop" | "typedef" => {
+                let name_length = get_name_length(content);
+                let description = content[name_length..].trim().trim_start_matches('-').trim();
+                (Some(content[..name_length].to_string()), description)
+            }
+            _ => (None, content),
+        };
+
+        tags.push(JsDocTag {
+            tag,
+            type_expr,
+            name: name.filter(|name| !name.is_empty()),
+            description: description.split_whitespace().collect::<Vec<&str>>().join(" "),
+        });
+
+        rest = remaining;
+    }
+
+    tags
+}
+
+fn get_braced(text: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn get_name_length(text: &str) -> usize {
+    match text.starts_with('[') {
+        true => text.find(']').map(|index| index + 1).unwrap_or(text.len()),
+        false => text.find(char::is_whitespace).unwrap_or(text.len()),
+    }
+}
+
+fn get_property(tag: &JsDocTag) -> Option<JsDocProperty> {
+    let name = tag.name.clone()?;
+    let mut type_expr = tag.type_expr.clone();
+    let mut optional = false;
+
+    let name = match name.strip_prefix('[').and_then(|name| name.strip_suffix(']')) {
+        Some(name) => {
+            optional = true;
+            name.split('=').next().unwrap_or_default().trim().to_string()
+        }
+        None => name,
+    };
+
+    if let Some(required_type) = type_expr.as_deref().and_then(|type_expr| type_expr.strip_suffix('=')) {
+        optional = true;
+        type_expr = Some(required_type.to_string());
+    }
+
+    Some(JsDocProperty {
+        path: name.split('.').map(|part| part.to_string()).collect(),
+        type_expr,
+        optional,
+        description: tag.description.clone(),
+    })
+}
+
+fn get_params(tags: &[JsDocTag]) -> Vec<JsDocProperty> {
+    tags.iter()
+        .filter(|tag| matches!(tag.tag.as_str(), "param" | "arg" | "argument"))
+        .filter_map(get_property)
+        .collect()
+}
+
+fn get_returns(tags: &[JsDocTag]) -> Option<String> {
+    tags.iter()
+        .find(|tag| matches!(tag.tag.as_str(), "returns" | "return"))
+        .and_then(|tag| tag.type_expr.clone())
+}
+
+fn get_import_type(text: &str) -> Option<(String, String, usize)> {
+    let rest = text.strip_prefix("import(")?.trim_start();
+    let quote = rest.chars().next().filter(|c| matches!(c, '\'' | '"' | '`'))?;
+    let specifier_length = rest[1..].find(quote)?;
+    let specifier = rest[1..specifier_length + 1].to_string();
+
+    let rest = rest[specifier_length + 2..]
+        .trim_start()
+        .strip_prefix(')')?
+        .strip_prefix('.')?;
+    let name_length = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(rest.len());
+    if name_length == 0 {
+        return None;
+    }
+
+    let name = rest[..name_length].to_string();
+    let length = text.len() - rest.len() + name_length;
+    Some((specifier, name, length))
+}
+
+fn replace_word(text: &str, from: &str, to: &str) -> String {
+    let mut replaced = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find(from) {
+        let is_word_start = !rest[..index]
+            .chars()
+            .last()
+            .map_or(false, |c| c.is_alphanumeric() || c == '_' || c == '$' || c == '.');
+        let end = index + from.len();
+        let is_word_end = from.ends_with('<')
+            || !rest[end..]
+                .chars()
+                .next()
+                .map_or(false, |c| c.is_alphanumeric() || c == '_' || c == '$' || c == '.');
+
+        replaced.push_str(&rest[..index]);
+        match is_word_start && is_word_end {
+            true => replaced.push_str(to),
+            false => replaced.push_str(from),
+        }
+
+        rest = &rest[end..];
+    }
+
+    replaced.push_str(rest);
+    replaced
+}
+
+fn is_valid_type(type_expr: &str) -> bool {
+    let source = format!("type T = {};", type_expr);
+    let end_pos = BytePos(1 + source.len() as u32);
+    let lexer = Lexer::new(
+        Syntax::Typescript(Default::default()),
+        Default::default(),
+        StringInput::new(&source, BytePos(1), end_pos),
+        None,
+    );
+
+    let mut parser = Parser::new_from(lexer);
+    let is_valid = parser.parse_module().is_ok();
+    is_valid && parser.take_errors().is_empty()
+}
@@ -1,5 +1,6 @@
+mod jsdoc;
 mod module_cache;
 mod node;
 
-pub use module_cache::ModuleCache;
+pub use module_cache::{CompilerOptions, ModuleCache};
 pub use node::{NodeKind, SchemyNode};
@@ -1,59 +1,271 @@
 use std::{collections::BTreeMap, path::Path, rc::Rc};
 use swc_common::{
-    errors::{ColorConfig, Handler},
-    sync::Lrc,
-    SourceMap,
+    comments::SingleThreadedComments, sync::Lrc, BytePos, FileName, SourceFile, SourceMap, Span, Spanned, DUMMY_SP,
 };
 
-use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax};
+use swc_ecma_ast::{EsVersion, Module};
+use swc_ecma_parser::{error::Error, lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
 
-use super::SchemyNode;
+use super::{jsdoc::annotate_jsdoc, SchemyNode};
 
 pub struct ModuleCache {
     cm: Lrc<SourceMap>,
     cache: BTreeMap<String, Rc<SchemyNode<'static>>>,
+    comments: BTreeMap<String, SingleThreadedComments>,
+    compiler_options: CompilerOptions,
+    errors: Vec<ModuleError>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CompilerOptions {
+    pub jsx: bool,
+    pub target: EsVersion,
 }
 
 impl<'m> ModuleCache {
-    pub fn new() -> Self {
+    pub fn new(compiler_options: CompilerOptions) -> Self {
         Self {
             cm: Default::default(),
             cache: BTreeMap::new(),
+            comments: BTreeMap::new(),
+            compiler_options,
+            errors: Vec::new(),
         }
     }
 
+    pub fn contains(&self, path: &str) -> bool {
+        self.cache.contains_key(path)
+    }
+
+    pub fn comments(&self, path: &str) -> Option<SingleThreadedComments> {
+        self.comments.get(path).cloned()
+    }
+
+    pub fn location(&self, position: BytePos) -> (usize, usize) {
+        let location = self.cm.lookup_char_pos(position);
+        (location.line, location.col_display + 1)
+    }
+
+    pub fn take_errors(&mut self) -> Vec<ModuleError> {
+        self.errors.drain(..).collect()
+    }
+
     pub fn parse(&mut self, path: &str) -> Rc<SchemyNode<'static>> {
-        self.cache.entry(path.to_string()).or_insert_with(|| {
-            let fm = self
-                .cm
-                .load_file(Path::new(path))
-                .expect(format!("Could not load file '{}'", path).as_str());
-            let handler = Handler::with_tty_emitter(ColorConfig::Auto, true, false, Some(self.cm.clone()));
+        if let Some(module) = self.cache.get(path) {
+            return module.clone();
+        }
+
+        // modules that fail to load are cached as empty modules so they are only reported once
+        let module = match self.parse_module(path) {
+            Ok(module) => module,
+            Err(error) => {
+                self.errors.push(error);
+                Module {
+                    span: DUMMY_SP,
+                    body: vec![],
+                    shebang: None,
+                }
+            }
+        };
+
+        let root = SchemyNode::from_module(module);
+        self.cache.insert(path.to_string(), root.clone());
+        root
+    }
 
+    fn parse_module(&mut self, path: &str) -> Result<Module, ModuleError> {
+        let mut fm = self.cm.load_file(Path::new(path)).map_err(|error| ModuleError {
+            file_path: path.to_string(),
+            message: format!("Could not load file '{}': {}", path, error),
+            span: None,
+        })?;
+
+        let syntax = self.get_syntax(path);
+        if is_javascript(path) {
+            let comments = SingleThreadedComments::default();
             let lexer = Lexer::new(
-                Syntax::Typescript(Default::default()),
-                Default::default(),
+                syntax,
+                self.compiler_options.target,
                 StringInput::from(&*fm),
-                None,
+                Some(&comments),
+            );
+
+            // modules with syntax errors are left unannotated and reported below
+            if let Ok(module) = Parser::new_from(lexer).parse_module() {
+                let source = annotate_jsdoc(&fm, module, &comments);
+                fm = self.cm.new_source_file(FileName::Real(path.into()), source);
+            }
+        }
+
+        let mut end_pos = fm.end_pos;
+        let mut recovering = false;
+        loop {
+            let comments = SingleThreadedComments::default();
+            let source = &fm.src[..(end_pos - fm.start_pos).0 as usize];
+
+            let lexer = Lexer::new(
+                syntax,
+                self.compiler_options.target,
+                StringInput::new(source, fm.start_pos, end_pos),
+                Some(&comments),
             );
 
             let mut parser = Parser::new_from(lexer);
+            let result = parser.parse_module();
+
+            // errors are only reported for the whole file, not for the truncated sources parsed while recovering
+            if !recovering {
+                for error in parser.take_errors() {
+                    self.errors.push(ModuleError::from_syntax_error(path, error));
+                }
+            }
+
+            match result {
+                Ok(module) => {
+                    self.comments.insert(path.to_string(), comments);
+                    return Ok(module);
+                }
+                Err(error) => {
+                    let span = error.span();
+                    if !recovering {
+                        let target = self.compiler_options.target;
+                        let error = find_declaration_error(&fm, syntax, target, &error, end_pos).unwrap_or(error);
+                        self.errors.push(ModuleError::from_syntax_error(path, error));
+                        recovering = true;
+                    }
+
+                    // keep the module items that come before the one that could not be parsed
+                    end_pos = get_recovery_position(&fm, span.lo, end_pos);
+                }
+            }
+        }
+    }
+}
+
+impl ModuleCache {
+    fn get_syntax(&self, path: &str) -> Syntax {
+        let path = Path::new(path);
+        let file_name = path
+            .file_name()
+            .and_then(|file_name| file_name.to_str())
+            .unwrap_or_default();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        Syntax::Typescript(TsConfig {
+            tsx: match extension {
+                Some("tsx") | Some("jsx") => true,
+                Some("js") | Some("cjs") | Some("mjs") => self.compiler_options.jsx,
+                _ => false,
+            },
+            // decorators are always parsed, both legacy and standard ones are valid TypeScript
+            decorators: true,
+            dts: file_name.ends_with(".d.ts") || file_name.ends_with(".d.mts") || file_name.ends_with(".d.cts"),
+            // type assertions like <T>value are not allowed in ES modules and CommonJS modules
+            disallow_ambiguous_jsx_like: matches!(extension, Some("mts") | Some("cts")),
+            ..Default::default()
+        })
+    }
+}
 
-            for e in parser.take_errors() {
-                e.into_diagnostic(&handler).emit();
+fn is_javascript(path: &str) -> bool {
+    match Path::new(path).extension().and_then(|extension| extension.to_str()) {
+        Some("js") | Some("cjs") | Some("mjs") | Some("jsx") => true,
+        _ => false,
+    }
+}
+
+// swc backs out of a TypeScript declaration that fails to parse after `export` or `declare` and reports the
+// keyword that follows them, so the declaration is parsed again without them to find the error inside it
+fn find_declaration_error(
+    fm: &SourceFile,
+    syntax: Syntax,
+    target: EsVersion,
+    error: &Error,
+    end_pos: BytePos,
+) -> Option<Error> {
+    let start_pos = get_recovery_position(fm, error.span().lo, end_pos);
+    let mut source = fm.src[(start_pos - fm.start_pos).0 as usize..(end_pos - fm.start_pos).0 as usize].to_string();
+
+    let mut offset = 0;
+    let mut blanked = false;
+    loop {
+        let rest = &source[offset..];
+        offset += rest.len() - rest.trim_start().len();
+        match ["export ", "declare "]
+            .iter()
+            .find(|keyword| source[offset..].starts_with(*keyword))
+        {
+            Some(keyword) => {
+                // blanking the keywords keeps every position in the declaration where it was
+                source.replace_range(offset..offset + keyword.len(), &" ".repeat(keyword.len()));
+                offset += keyword.len();
+                blanked = true;
             }
+            None => break,
+        }
+    }
 
-            let module = parser
-                .parse_module()
-                .map_err(|e| {
-                    // Unrecoverable fatal error occurred
-                    e.into_diagnostic(&handler).emit()
-                })
-                .expect(format!("Could not parse module '{}'", path).as_str());
+    if !blanked {
+        return None;
+    }
+
+    let lexer = Lexer::new(syntax, target, StringInput::new(&source, start_pos, end_pos), None);
+    let mut parser = Parser::new_from(lexer);
+    let result = parser.parse_module();
+    parser
+        .take_errors()
+        .into_iter()
+        .chain(result.err())
+        .min_by_key(|declaration_error| declaration_error.span().lo)
+        .filter(|declaration_error| declaration_error.span().lo > error.span().lo)
+}
 
-            SchemyNode::from_module(module)
-        });
+fn get_recovery_position(fm: &SourceFile, error_pos: BytePos, end_pos: BytePos) -> BytePos {
+    let position = match error_pos < end_pos && error_pos >= fm.start_pos {
+        true => error_pos,
+        false => end_pos - BytePos(1),
+    };
 
-        self.cache.get(path).unwrap().clone()
+    let mut line_index = match fm.lookup_line(position) {
+        Some(line_index) => line_index,
+        None => return fm.start_pos,
+    };
+
+    loop {
+        let (line_start, line_end) = fm.line_bounds(line_index);
+        let line = &fm.src[(line_start - fm.start_pos).0 as usize..(line_end - fm.start_pos).0 as usize];
+
+        // module items start at the beginning of a line, unlike their bodies, closing brackets and comments
+        let starts_item = match line.chars().next() {
+            Some(first) => !first.is_whitespace() && !"})]/*".contains(first),
+            None => false,
+        };
+
+        if starts_item && line_start < end_pos {
+            return line_start;
+        }
+
+        if line_index == 0 {
+            return fm.start_pos;
+        }
+
+        line_index -= 1;
+    }
+}
+
+#[derive(Debug)]
+pub struct ModuleError {
+    pub file_path: String,
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+impl ModuleError {
+    fn from_syntax_error(path: &str, error: Error) -> Self {
+        Self {
+            file_path: path.to_string(),
+            message: error.kind().msg().to_string(),
+            span: Some(error.span()),
+        }
     }
 }
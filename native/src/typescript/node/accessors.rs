@@ -3,6 +3,9 @@ use std::{
     vec,
 };
 
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{Expr, TsFnOrConstructorType};
+
 use super::{NodeKind, SchemyNode};
 
 impl<'m> SchemyNode<'m> {
@@ -109,6 +112,72 @@ impl<'m> SchemyNode<'m> {
         self.parent.as_ref().map(|p| Weak::upgrade(p).unwrap())
     }
 
+    pub fn span(self: &Rc<Self>) -> Option<Span> {
+        let span = match self.kind {
+            NodeKind::Expr(raw) => raw.span(),
+            NodeKind::Ident(raw) => raw.span,
+            NodeKind::TsExprWithTypeArgs(raw) => raw.span,
+            NodeKind::TsType(raw) => raw.span(),
+            NodeKind::TsTypeAnnotation(raw) => raw.span,
+            NodeKind::TsTypeRef(raw) => raw.span,
+            NodeKind::TsInterfaceDecl(raw) => raw.span,
+            NodeKind::TsTypeAliasDecl(raw) => raw.span,
+            NodeKind::TsEnumDecl(raw) => raw.span,
+            NodeKind::ClassDecl(raw) => raw.class.span,
+            NodeKind::Class(raw) => raw.span,
+            NodeKind::CallExpr(raw) => raw.span,
+            NodeKind::NewExpr(raw) => raw.span,
+            NodeKind::TsMappedType(raw) => raw.span,
+            NodeKind::TsConditionalType(raw) => raw.span,
+            _ => return self.parent().and_then(|parent| parent.span()),
+        };
+
+        Some(span)
+    }
+
+    pub fn return_type(self: &Rc<Self>) -> Option<Rc<SchemyNode<'m>>> {
+        match self.kind {
+            NodeKind::ArrowExpr(_) => {
+                for child in self.children() {
+                    match child.kind {
+                        NodeKind::TsTypeAnnotation(_) => return Some(child.clone()),
+                        _ => {}
+                    }
+                }
+
+                None
+            }
+            NodeKind::FnDecl(raw) => raw
+                .function
+                .return_type
+                .as_ref()
+                .map(|r| self.to_child(NodeKind::TsTypeAnnotation(r))),
+            NodeKind::FnExpr(raw) => raw
+                .function
+                .return_type
+                .as_ref()
+                .map(|r| self.to_child(NodeKind::TsTypeAnnotation(r))),
+            NodeKind::Method(raw) => raw
+                .function
+                .return_type
+                .as_ref()
+                .map(|r| self.to_child(NodeKind::TsTypeAnnotation(r))),
+            NodeKind::TsFnOrConstructorType(TsFnOrConstructorType::TsFnType(raw)) => {
+                Some(self.to_child(NodeKind::TsTypeAnnotation(&raw.type_ann)))
+            }
+            NodeKind::Expr(Expr::Arrow(raw)) => raw
+                .return_type
+                .as_ref()
+                .map(|r| self.to_child(NodeKind::TsTypeAnnotation(r))),
+            NodeKind::Expr(Expr::Fn(raw)) => raw
+                .function
+                .return_type
+                .as_ref()
+                .map(|r| self.to_child(NodeKind::TsTypeAnnotation(r))),
+            _ => None,
+        }
+    }
+
     pub fn specifiers(self: &Rc<Self>) -> Vec<Rc<SchemyNode<'m>>> {
         let mut specificers = vec![];
         for child in self.children() {
@@ -10,6 +10,7 @@ impl<'m> SchemyNode<'m> {
         match self.kind {
             NodeKind::AwaitExpr(raw) => self.get_await_expr_children(raw, &mut children),
             NodeKind::ArrowExpr(raw) => self.get_arrow_expr_children(raw, &mut children),
+            NodeKind::AssignExpr(raw) => self.get_assign_expr_children(raw, &mut children),
             NodeKind::BindingIdent(raw) => self.get_binding_ident_children(raw, &mut children),
             NodeKind::BlockStmt(raw) => self.get_block_statement_children(raw, &mut children),
             NodeKind::BlockStmtOrExpr(temp) => match temp {
@@ -45,6 +46,8 @@ impl<'m> SchemyNode<'m> {
             NodeKind::NewExpr(raw) => self.get_new_expr_children(raw, &mut children),
             NodeKind::Pat(raw) => self.get_pat_children(raw, &mut children),
             NodeKind::ReturnStmt(raw) => self.get_return_statement_children(raw, &mut children),
+            NodeKind::SwitchCase(raw) => self.get_switch_case_children(raw, &mut children),
+            NodeKind::SwitchStmt(raw) => self.get_switch_statement_children(raw, &mut children),
             NodeKind::TryStmt(raw) => self.get_try_statement_children(raw, &mut children),
             NodeKind::TsArrayType(raw) => self.get_ts_array_type_children(raw, &mut children),
             NodeKind::TsAsExpr(raw) => self.get_ts_as_expr_children(raw, &mut children),
@@ -231,6 +234,24 @@ impl<'m> SchemyNode<'m> {
         }
     }
 
+    fn get_switch_statement_children(self: &Rc<Self>, raw: &'m SwitchStmt, children: &mut Vec<Rc<SchemyNode<'m>>>) {
+        self.get_expr_children(&*raw.discriminant, children);
+        for case in &raw.cases {
+            let kind = NodeKind::SwitchCase(case);
+            self.push_children(kind, children);
+        }
+    }
+
+    fn get_switch_case_children(self: &Rc<Self>, raw: &'m SwitchCase, children: &mut Vec<Rc<SchemyNode<'m>>>) {
+        for statement in &raw.cons {
+            self.get_statement_children(statement, children);
+        }
+    }
+
+    fn get_assign_expr_children(self: &Rc<Self>, raw: &'m AssignExpr, children: &mut Vec<Rc<SchemyNode<'m>>>) {
+        self.get_expr_children(&*raw.right, children);
+    }
+
     fn get_try_statement_children(self: &Rc<Self>, raw: &'m TryStmt, children: &mut Vec<Rc<SchemyNode<'m>>>) {
         self.get_block_statement_children(&raw.block, children);
 
@@ -1012,6 +1033,11 @@ impl<'m> SchemyNode<'m> {
             let kind = NodeKind::Pat(param);
             self.push_children(kind, children);
         });
+
+        if let Some(return_type) = &expr.return_type {
+            let kind = NodeKind::TsTypeAnnotation(return_type);
+            self.push_children(kind, children);
+        }
     }
 
     fn get_module_children(self: &Rc<Self>, module: &'m Module, children: &mut Vec<Rc<SchemyNode<'m>>>) {
@@ -1115,8 +1141,10 @@ impl<'m> SchemyNode<'m> {
     fn get_pat_children(self: &Rc<Self>, pat: &'m Pat, children: &mut Vec<Rc<SchemyNode<'m>>>) {
         match pat {
             Pat::Ident(ident) => {
-                let kind = NodeKind::TsTypeAnnotation(ident.type_ann.as_ref().unwrap());
-                self.push_children(kind, children);
+                if let Some(type_ann) = &ident.type_ann {
+                    let kind = NodeKind::TsTypeAnnotation(type_ann);
+                    self.push_children(kind, children);
+                }
             }
             Pat::Array(raw) => {
                 let kind = NodeKind::ArrayPat(raw);
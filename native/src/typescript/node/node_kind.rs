@@ -82,6 +82,7 @@ pub enum NodeKind<'m> {
     ObjectLit(&'m ObjectLit),
     Pat(&'m Pat),
     ReturnStmt(&'m ReturnStmt),
+    SwitchCase(&'m SwitchCase),
     SwitchStmt(&'m SwitchStmt),
     ThrowStmt(&'m ThrowStmt),
     TryStmt(&'m TryStmt),
@@ -219,6 +220,7 @@ impl<'n> Clone for NodeKind<'n> {
             NodeKind::SeqExpr(raw) => NodeKind::SeqExpr(raw),
             NodeKind::Str(raw) => NodeKind::Str(raw),
             NodeKind::SuperPropExpr(raw) => NodeKind::SuperPropExpr(raw),
+            NodeKind::SwitchCase(raw) => NodeKind::SwitchCase(*raw),
             NodeKind::SwitchStmt(raw) => NodeKind::SwitchStmt(*raw),
             NodeKind::TaggedTpl(raw) => NodeKind::TaggedTpl(raw),
             NodeKind::TemplateLiteral(raw) => NodeKind::TemplateLiteral(raw),
@@ -335,6 +337,7 @@ impl<'m> Debug for NodeKind<'m> {
             NodeKind::ObjectLit(_) => f.debug_tuple("ObjectLit").finish(),
             NodeKind::Pat(_) => f.debug_tuple("Pat").finish(),
             NodeKind::ReturnStmt(_) => f.debug_tuple("ReturnStmt").finish(),
+            NodeKind::SwitchCase(_) => f.debug_tuple("SwitchCase").finish(),
             NodeKind::SwitchStmt(_) => f.debug_tuple("SwitchStmt").finish(),
             NodeKind::ThrowStmt(_) => f.debug_tuple("ThrowStmt").finish(),
             NodeKind::TryStmt(_) => f.debug_tuple("TryStmt").finish(),
@@ -1,27 +1,68 @@
 import { Command } from 'commander';
 import path from 'path';
+import ts from 'typescript';
 import { generateSchemas, LilSchemyOptions as LilSchemyOptions, LilSchemyResult as LilSchemyResult } from '../generator';
-import { getRootFiles } from '../utils';
+import { getCompilerOptions, getRootFiles } from '../utils';
 
 export const generate = (cwd: string, options: LilSchemyOptions): LilSchemyResult => {
-    const { openApi } = options;
+    const { openApi, jsonSchema } = options;
 
     const files = getRootFiles(cwd, openApi?.entry ?? []);
+    const compilerOptions = getCompilerOptions(cwd, options.tsconfig);
 
     console.debug("Searching for api paths in files %o", files);
 
     const result = generateSchemas({
+        compilerOptions: {
+            jsx: compilerOptions.jsx === undefined ? undefined : ts.JsxEmit[compilerOptions.jsx],
+            target: compilerOptions.target === undefined ? undefined : ts.ScriptTarget[compilerOptions.target],
+        },
         openApi: {
             base: JSON.stringify(openApi?.base ?? {}),
             entry: files,
-            output: openApi?.output
+            output: openApi?.output,
+            parameterForm: openApi?.parameterForm,
+            inferResponses: openApi?.inferResponses,
+            returnTypeResponses: openApi?.returnTypeResponses,
+            returnTypeDescription: openApi?.returnTypeDescription,
+            hoistSchemas: openApi?.hoistSchemas,
+            responseSchemaName: openApi?.responseSchemaName,
+            bodySchemaName: openApi?.bodySchemaName,
+            schemaNameCollision: openApi?.schemaNameCollision,
+            format: openApi?.format,
+            indent: openApi?.indent,
+            openApiVersion: openApi?.openApiVersion,
+            failOnInvalid: openApi?.failOnInvalid,
+            unresolvedReferences: openApi?.unresolvedReferences,
+        },
+        jsonSchema: jsonSchema && {
+            entry: getRootFiles(cwd, jsonSchema.entry),
+            types: jsonSchema.types,
+            output: jsonSchema.output,
+            bundle: jsonSchema.bundle,
+            id: jsonSchema.id,
+            indent: jsonSchema.indent,
+            unresolvedReferences: jsonSchema.unresolvedReferences,
         }
     });
 
+    for (const diagnostic of result.diagnostics ?? []) {
+        const location = [diagnostic.file, diagnostic.line, diagnostic.column].filter(part => part !== undefined).join(":");
+        console.warn("%s %s: %s", diagnostic.severity, location, diagnostic.message);
+    }
+
+    for (const error of result.openApi?.errors ?? []) {
+        console.warn("OpenApi schema is invalid at %s", error);
+    }
+
     if (result.openApi?.filepath) {
         console.info("OpenApi schema written to %s", result.openApi.filepath);
     }
 
+    for (const filepath of result.jsonSchema?.filepaths ?? []) {
+        console.info("JSON Schema written to %s", filepath);
+    }
+
     return result;
 };
 
@@ -2,26 +2,74 @@ import { OpenAPIV3 } from 'openapi-types';
 
 export interface LilSchemyOptions {
     openApi?: OpenApiOptions;
+    jsonSchema?: JsonSchemaOptions;
+    tsconfig?: string;
 }
 
 interface OpenApiOptions {
     base: Omit<OpenAPIV3.Document, "openapi"> & {
-        openapi: "3.0.3";
+        openapi: "3.0.3" | "3.1.0";
     },
     output?: string;
     entry: string[];
+    parameterForm?: "schema" | "content";
+    inferResponses?: boolean;
+    returnTypeResponses?: boolean;
+    returnTypeDescription?: string;
+    hoistSchemas?: boolean;
+    responseSchemaName?: string;
+    bodySchemaName?: string;
+    schemaNameCollision?: "error" | "prefix" | "suffix";
+    format?: "json" | "yaml";
+    indent?: number;
+    openApiVersion?: "3.0.3" | "3.1.0";
+    failOnInvalid?: boolean;
+    unresolvedReferences?: "empty" | "error";
+}
+
+interface JsonSchemaOptions {
+    entry: string[];
+    types?: string[];
+    output?: string;
+    bundle?: boolean;
+    id?: string;
+    indent?: number;
+    unresolvedReferences?: "empty" | "error";
 }
 
 export interface LilSchemyResult {
     openApi: OpenApiResult;
+    jsonSchema?: JsonSchemaResult;
+    diagnostics: Diagnostic[];
+}
+
+export interface Diagnostic {
+    severity: "error" | "warning";
+    message: string;
+    file?: string;
+    line?: number;
+    column?: number;
 }
 
 export interface OpenApiResult {
     schema?: string;
     filepath?: string;
+    errors?: string[];
+}
+
+export interface JsonSchemaResult {
+    schemas?: { [name: string]: string };
+    schema?: string;
+    filepaths?: string[];
+}
+
+interface CompilerOptions {
+    jsx?: string;
+    target?: string;
 }
 
-interface GenerateSchemaArgs extends Omit<LilSchemyOptions, "cwd"> {
+interface GenerateSchemaArgs extends Omit<LilSchemyOptions, "cwd" | "tsconfig"> {
+    compilerOptions?: CompilerOptions;
     // getAst: (reference: string, moduleFileName: string) => string | undefined;
     openApi?: Omit<OpenApiOptions, "base"> & {
         base: string;
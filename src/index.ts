@@ -30,6 +30,7 @@ export type OperationMethod = 'GET' | 'PUT' | 'POST' | 'DELETE' | 'OPTIONS' | 'H
 export interface PathItemOptions {
     method: OperationMethod;
     path: string;
+    operationId?: string;
     tags?: string[];
 }
 export function LilPath<Func>(fn: Func, options: PathItemOptions) {
@@ -40,15 +41,26 @@ export interface ResponseOptions {
     description: string;
     example?: string;
     statusCode: number;
-    mediaType?: string;
+    mediaType?: string | string[];
 }
 export function LilResponse<ResponseType>(response: ResponseType, options: ResponseOptions) {
     return response;
 }
 
+export type ParamStyle = "matrix" | "label" | "form" | "simple" | "spaceDelimited" | "pipeDelimited" | "deepObject";
+
+export type LilParamStyle<Style extends ParamStyle, Explode extends boolean | undefined = undefined, AllowReserved extends boolean | undefined = undefined> = {
+    style: Style;
+    explode: Explode;
+    allowReserved: AllowReserved;
+};
+
 export type LilBodyParam<Param, Required extends boolean = true, MediaType extends string = "application/json"> = Param;
-export type LilHeader<Param, Required extends boolean = true, Format extends format = undefined> = Param;
-export type LilQueryParam<Param, Required extends boolean = false, Format extends format = undefined> = Param;
-export type LilRouteParam<Param, Required extends true = true, Format extends format = undefined> = Param;
+export type LilHeader<Param, Required extends boolean = true, Format extends format = undefined, Style = undefined> = Param;
+export type LilQueryParam<Param, Required extends boolean = false, Format extends format = undefined, Style = undefined> = Param;
+export type LilRouteParam<Param, Required extends true = true, Format extends format = undefined, Style = undefined> = Param;
+export type LilHeaders<Params extends object> = Params;
+export type LilQueryParams<Params extends object> = Params;
 export type LilRequiredProp<Param> = Param;
-export type LilSub<From, To> = From;
\ No newline at end of file
+export type LilSub<From, To> = From;
+export type LilSchema<Type> = Type;
\ No newline at end of file
@@ -1,5 +1,6 @@
 import { sync as fg } from 'fast-glob';
 import { readFileSync } from 'fs';
+import path from 'path';
 import ts, { CompilerOptions } from 'typescript';
 
 let cache: ts.ModuleResolutionCache;
@@ -38,6 +39,16 @@ export const getRootFiles = (cwd: string, globs: string[]): string[] => fg(globs
     cwd
 });
 
+export const getCompilerOptions = (cwd: string, tsconfig?: string): CompilerOptions => {
+    const configPath = tsconfig ? path.resolve(cwd, tsconfig) : ts.findConfigFile(cwd, ts.sys.fileExists);
+    if (!configPath) {
+        return {};
+    }
+
+    const { config } = ts.readConfigFile(configPath, ts.sys.readFile);
+    return ts.parseJsonConfigFileContent(config ?? {}, ts.sys, path.dirname(configPath)).options;
+};
+
 // const _getAst = (p: string, external: boolean): ts.Node => {
 //     const file = readFileSync(p, {
 //         encoding: 'utf-8',
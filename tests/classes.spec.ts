@@ -0,0 +1,71 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api class schemas', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/classes/pets.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+    const { Entity, Pet } = schema.components.schemas;
+
+    it('combines base classes with allOf', () => {
+        expect(Pet.allOf).to.have.length(2);
+        expect(Pet.allOf[0]).to.deep.equal({ $ref: "#/components/schemas/Entity" });
+        expect(Entity).to.deep.equal({
+            type: "object",
+            properties: {
+                id: { type: "string" }
+            }
+        });
+    });
+
+    it('includes public fields, parameter properties and getters', () => {
+        expect(Pet.allOf[1]).to.deep.equal({
+            type: "object",
+            properties: {
+                species: { type: "string", readOnly: true },
+                nickname: { type: "string" },
+                name: { type: "string" },
+                born: { type: "string", readOnly: true },
+                age: { type: "number", readOnly: true },
+                label: { type: "string" },
+            }
+        });
+    });
+
+    it('looks up inherited properties through the extends chain', () => {
+        const { PetReference } = schema.components.schemas;
+
+        expect(PetReference.properties.id).to.deep.equal({ type: "string" });
+        expect(PetReference.properties.field).to.deep.equal({
+            type: "string",
+            enum: ["id", "species", "nickname", "name", "born", "age", "label", "speak"]
+        });
+    });
+
+    it('binds the type parameters of a generic base class', () => {
+        expect(schema.components.schemas.NamedBox.allOf).to.deep.equal([
+            { type: "object", properties: { value: { type: "string" } } },
+            { type: "object", properties: { name: { type: "string" } } },
+        ]);
+        expect(schema.components.schemas.T).to.not.exist;
+    });
+
+    it('leaves out a base class that could not be evaluated', () => {
+        expect(schema.components.schemas.MixedPet).to.deep.equal({
+            type: "object",
+            properties: {
+                name: { type: "string" }
+            }
+        });
+        expect(result.diagnostics).to.have.lengthOf(1);
+        expect(result.diagnostics[0]).to.deep.include({
+            severity: "warning",
+            message: "Could not evaluate the base class, only its own properties are included",
+        });
+    });
+});
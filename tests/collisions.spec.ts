@@ -0,0 +1,47 @@
+import { expect } from 'chai';
+import { OpenAPIV3 } from 'openapi-types';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api schema name collisions', () => {
+    const generate = (schemaNameCollision?: "error" | "prefix" | "suffix"): OpenAPIV3.Document => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({}),
+                entry: getRootFiles(__dirname, ["test-api/collisions/*.ts"]),
+                schemaNameCollision,
+            }
+        });
+
+        return JSON.parse(result.openApi.schema || "");
+    };
+
+    const getBodyReference = (schema: OpenAPIV3.Document, path: string) => {
+        const body = schema.paths[path]?.post?.requestBody as OpenAPIV3.RequestBodyObject;
+        return (body.content["application/json"].schema as OpenAPIV3.ReferenceObject).$ref;
+    };
+
+    it('suffixes the later schema by default', () => {
+        const schema = generate();
+
+        expect(getBodyReference(schema, "/billing/users")).to.equal("#/components/schemas/User");
+        expect(getBodyReference(schema, "/auth/users")).to.equal("#/components/schemas/User2");
+
+        const billingUser = schema.components?.schemas?.User as OpenAPIV3.SchemaObject;
+        const authUser = schema.components?.schemas?.User2 as OpenAPIV3.SchemaObject;
+        expect(Object.keys(billingUser.properties || {})).to.deep.equal(["accountId", "balance"]);
+        expect(Object.keys(authUser.properties || {})).to.deep.equal(["email", "roles"]);
+    });
+
+    it('prefixes the later schema with its module name', () => {
+        const schema = generate("prefix");
+
+        expect(getBodyReference(schema, "/billing/users")).to.equal("#/components/schemas/User");
+        expect(getBodyReference(schema, "/auth/users")).to.equal("#/components/schemas/AuthDtosUser");
+        expect(Object.keys(schema.components?.schemas || {})).to.have.members(["User", "AuthDtosUser"]);
+    });
+
+    it('fails when collisions are errors', () => {
+        expect(() => generate("error")).to.throw("Schema name 'User' is declared in both");
+    });
+});
@@ -0,0 +1,32 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api commonjs modules', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/commonjs/invoices.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+
+    const getResponseSchema = (path: string) => {
+        const { $ref } = schema.paths[path].get.responses[200].content["application/json"].schema;
+        return schema.components.schemas[$ref.split("/").pop()];
+    };
+
+    it('resolves destructured require calls against module.exports and exports', () => {
+        expect(result.diagnostics).to.be.empty;
+        expect(Object.keys(getResponseSchema("/invoices").properties)).to.have.members(["number", "total"]);
+        expect(Object.keys(getResponseSchema("/invoice-lines").properties)).to.have.members(["description", "quantity"]);
+    });
+
+    it('resolves import require against export assignments', () => {
+        expect(Object.keys(getResponseSchema("/customers").properties)).to.have.members(["name"]);
+    });
+
+    it('resolves shorthand properties of module.exports', () => {
+        expect(Object.keys(getResponseSchema("/users").properties)).to.have.members(["email"]);
+    });
+});
@@ -0,0 +1,65 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api derived utility types', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/derived/users.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+    const { UserSummary } = schema.components.schemas;
+
+    it('evaluates ReturnType against methods and functions', () => {
+        expect(UserSummary.properties.user).to.deep.equal({ $ref: "#/components/schemas/User" });
+        expect(UserSummary.properties.users).to.deep.equal({
+            type: "array",
+            items: { $ref: "#/components/schemas/User" }
+        });
+        expect(UserSummary.properties.count).to.deep.equal({ type: "number" });
+        expect(schema.components.schemas.User).to.not.be.undefined;
+        expect(schema.components.schemas.ReturnType).to.be.undefined;
+    });
+
+    it('evaluates Parameters as a tuple of parameter types', () => {
+        expect(UserSummary.properties.lookup).to.deep.equal({
+            type: "array",
+            prefixItems: [{ type: "string" }, { type: "boolean" }],
+            minItems: 1,
+            items: false
+        });
+        expect(UserSummary.properties.search).to.deep.equal({
+            type: "array",
+            prefixItems: [{ type: "string" }],
+            minItems: 1,
+            items: { type: "string" }
+        });
+    });
+
+    it('evaluates tuples like Parameters', () => {
+        expect(UserSummary.properties.range).to.deep.equal({
+            type: "array",
+            prefixItems: [{ type: "number" }, { type: "number" }],
+            minItems: 2,
+            items: false
+        });
+        expect(UserSummary.properties.labels).to.deep.equal({
+            type: "array",
+            prefixItems: [{ type: "string" }, { type: "number" }],
+            minItems: 1,
+            items: { type: "boolean" }
+        });
+    });
+
+    it('evaluates InstanceType as the class schema', () => {
+        expect(UserSummary.properties.model).to.deep.equal({ $ref: "#/components/schemas/UserModel" });
+        expect(schema.components.schemas.UserModel.properties).to.have.keys("id", "email");
+    });
+
+    it('warns when the type argument is not a function', () => {
+        const diagnostic = result.diagnostics.find(diagnostic => diagnostic.message.includes("ReturnType"));
+        expect(diagnostic?.severity).to.equal("warning");
+    });
+});
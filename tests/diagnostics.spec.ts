@@ -0,0 +1,54 @@
+import { expect } from 'chai';
+import path from 'path';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('diagnostics', () => {
+    const missing = path.join(__dirname, "test-api/diagnostics/missing.ts");
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: [...getRootFiles(__dirname, ["test-api/diagnostics/*.ts"]), missing],
+        }
+    });
+
+    it('reports an unsupported http method and keeps generating', () => {
+        const schema = JSON.parse(result.openApi.schema || "");
+        const diagnostic = result.diagnostics.find(diagnostic => diagnostic.message.includes("CONNECT"));
+
+        expect(Object.keys(schema.paths["/tunnels"])).to.deep.equal(["get"]);
+        expect(diagnostic).to.deep.include({
+            severity: "error",
+            message: "Unsupported http method 'CONNECT' for path '/tunnels'",
+            line: 4,
+        });
+        expect(diagnostic?.file).to.match(/diagnostics[\\/]methods\.ts$/);
+    });
+
+    it('reports a call whose return type refers to itself', () => {
+        const schema = JSON.parse(result.openApi.schema || "");
+        const diagnostic = result.diagnostics.find(diagnostic => diagnostic.message.includes("refers back to itself"));
+
+        expect(schema.paths["/loop"].get.responses[200]).to.deep.equal({
+            description: "Loop",
+            content: {
+                "application/json": {
+                    schema: {}
+                }
+            }
+        });
+        expect(diagnostic).to.deep.include({
+            severity: "warning",
+            message: "Could not evaluate a call whose return type refers back to itself",
+        });
+        expect(diagnostic?.file).to.match(/diagnostics[\\/]calls\.ts$/);
+    });
+
+    it('reports a module that could not be loaded', () => {
+        const diagnostic = result.diagnostics.find(diagnostic => diagnostic.file === missing);
+
+        expect(diagnostic?.severity).to.equal("error");
+        expect(diagnostic?.message).to.match(/^Could not load file/);
+        expect(diagnostic?.line).to.be.undefined;
+    });
+});
@@ -0,0 +1,119 @@
+import { expect } from 'chai';
+import { OpenAPIV3 } from 'openapi-types';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api 3.0 output', () => {
+    let schema: OpenAPIV3.Document;
+
+    before(() => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({
+                    components: {
+                        schemas: {
+                            Limit: {
+                                type: ["integer", "null"],
+                                exclusiveMinimum: 0,
+                                examples: [10, 20]
+                            },
+                            Pair: {
+                                type: "array",
+                                prefixItems: [{ type: "string" }, { type: "string" }]
+                            },
+                            Arguments: {
+                                type: "array",
+                                prefixItems: [{ type: "string" }, { type: "number" }],
+                                minItems: 1,
+                                items: false
+                            },
+                            Lookup: {
+                                anyOf: [{ type: "string" }, { $ref: "#/components/schemas/Pair" }, { type: "null" }],
+                                default: { type: ["string", "null"], $id: "kept" }
+                            }
+                        }
+                    }
+                }),
+                entry: getRootFiles(__dirname, ["test-api/expressions/*.ts"]),
+                openApiVersion: "3.0.3",
+            }
+        });
+
+        schema = JSON.parse(result.openApi.schema || "");
+    });
+
+    it('sets OpenApi version', () => {
+        expect(schema.openapi).to.eq("3.0.3");
+    });
+
+    it('takes the version from the base schema', () => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({ openapi: "3.0.3" }),
+                entry: getRootFiles(__dirname, ["test-api/expressions/*.ts"]),
+            }
+        });
+        const downleveled = JSON.parse(result.openApi.schema || "");
+
+        expect(downleveled.openapi).to.eq("3.0.3");
+        expect(downleveled.paths["/orders/{id}"].get.responses[200].content["application/json"].schema.oneOf).to.have.length(2);
+    });
+
+    it('rejects an openApiVersion that conflicts with the base schema', () => {
+        expect(() => generateSchemas({
+            openApi: {
+                base: JSON.stringify({ openapi: "3.0.3" }),
+                entry: getRootFiles(__dirname, ["test-api/expressions/*.ts"]),
+                openApiVersion: "3.1.0",
+            }
+        })).to.throw("openApiVersion '3.1.0' does not match the base schema's openapi '3.0.3'");
+    });
+
+    it('pairs a reference in a null union with a schema that only accepts null', () => {
+        const response = schema.paths["/orders/{id}"]?.get?.responses[200] as OpenAPIV3.ResponseObject;
+
+        expect(response.content?.["application/json"].schema).to.deep.equal({
+            oneOf: [
+                { $ref: "#/components/schemas/Order" },
+                { type: "object", nullable: true, enum: [null] }
+            ]
+        });
+    });
+
+    it('makes each remaining typed union member nullable and leaves payloads alone', () => {
+        expect(schema.components?.schemas?.Lookup).to.deep.equal({
+            anyOf: [
+                { type: "string", nullable: true },
+                { $ref: "#/components/schemas/Pair" },
+                { type: "object", nullable: true, enum: [null] }
+            ],
+            default: { type: ["string", "null"], $id: "kept" }
+        });
+    });
+
+    it('removes schema ids', () => {
+        for (const component of Object.values(schema.components?.schemas || {})) {
+            expect(component).not.to.have.property("$id");
+        }
+    });
+
+    it('downlevels json schema keywords', () => {
+        expect(schema.components?.schemas?.Limit).to.deep.equal({
+            type: "integer",
+            nullable: true,
+            minimum: 0,
+            exclusiveMinimum: true,
+            example: 10
+        });
+        expect(schema.components?.schemas?.Pair).to.deep.equal({
+            type: "array",
+            items: { type: "string" }
+        });
+        expect(schema.components?.schemas?.Arguments).to.deep.equal({
+            type: "array",
+            items: { anyOf: [{ type: "string" }, { type: "number" }] },
+            minItems: 1,
+            maxItems: 2
+        });
+    });
+});
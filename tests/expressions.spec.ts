@@ -0,0 +1,129 @@
+import { expect, use } from 'chai';
+import deepEqual from 'deep-equal-in-any-order';
+import { OpenAPIV3 } from 'openapi-types';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+use(deepEqual);
+
+describe('open api expression inference', () => {
+    let schema: OpenAPIV3.Document;
+
+    before(() => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({}),
+                entry: getRootFiles(__dirname, ["test-api/expressions/orders.ts"]),
+            }
+        });
+
+        schema = JSON.parse(result.openApi.schema || "");
+    });
+
+    it('infers object literals property by property', () => {
+        expect(schema.paths["/orders/{id}/summary"]?.get?.responses).to.deep.equalInAnyOrder({
+            200: {
+                description: "Order summary",
+                content: {
+                    "application/json": {
+                        schema: {
+                            type: "object",
+                            properties: {
+                                ok: { type: "boolean" },
+                                order: { $ref: "#/components/schemas/Order" },
+                                customer: { $ref: "#/components/schemas/Customer" },
+                                total: { type: "number" },
+                                name: { type: "string" },
+                                tags: { type: "array", items: { type: "string" } }
+                            },
+                            required: ["customer", "name", "ok", "order", "tags", "total"]
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('infers conditional expressions as unions', () => {
+        expect(schema.paths["/orders/{id}"]?.get?.responses).to.deep.equal({
+            200: {
+                description: "Maybe an order",
+                content: {
+                    "application/json": {
+                        schema: {
+                            anyOf: [
+                                { $ref: "#/components/schemas/Order" },
+                                { type: "null" }
+                            ]
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('infers awaited calls from declared return types', () => {
+        expect(schema.paths["/orders"]?.get?.responses).to.deep.equal({
+            200: {
+                description: "All orders",
+                content: {
+                    "application/json": {
+                        schema: {
+                            type: "array",
+                            items: { $ref: "#/components/schemas/Order" }
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('infers satisfies and non-null expressions', () => {
+        expect(schema.paths["/customers/{id}"]?.get?.responses).to.deep.equal({
+            200: {
+                description: "A customer",
+                content: {
+                    "application/json": {
+                        schema: {
+                            $ref: "#/components/schemas/Customer"
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('keeps values and types that share a name apart', () => {
+        expect(schema.paths["/shipments"]?.get?.responses).to.deep.equalInAnyOrder({
+            200: {
+                description: "Shipment statuses",
+                content: {
+                    "application/json": {
+                        schema: {
+                            type: "object",
+                            properties: {
+                                shipment: { $ref: "#/components/schemas/Shipment" },
+                                statuses: {
+                                    type: "object",
+                                    properties: {
+                                        Open: { type: "string" },
+                                        Closed: { type: "string" }
+                                    },
+                                    required: ["Closed", "Open"]
+                                }
+                            },
+                            required: ["shipment", "statuses"]
+                        }
+                    }
+                }
+            }
+        });
+        expect(schema.components?.schemas?.Status).to.deep.equal({
+            anyOf: [{ enum: ["open", "closed"] }]
+        });
+    });
+
+    it('defines schemas referenced through expressions', () => {
+        expect(Object.keys(schema.components?.schemas || {})).to.include.members(["Order", "Customer"]);
+    });
+});
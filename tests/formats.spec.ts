@@ -0,0 +1,38 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api output formats', () => {
+    const generate = (options: { format?: "json" | "yaml", indent?: number }) => generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/expressions/*.ts"]),
+            ...options,
+        }
+    }).openApi.schema || "";
+
+    it('writes compact json by default', () => {
+        const schema = generate({});
+
+        expect(schema).to.match(/^\{"openapi":"3\.1\.0",/);
+        expect(schema).not.to.contain("\n");
+    });
+
+    it('indents json', () => {
+        const schema = generate({ indent: 2 });
+
+        expect(schema).to.match(/^\{\n  "openapi": "3\.1\.0",\n  "components": \{/);
+        expect(JSON.parse(schema)).to.deep.equal(JSON.parse(generate({})));
+    });
+
+    it('writes yaml in the same key order', () => {
+        const schema = generate({ format: "yaml" });
+
+        expect(schema).to.match(/^openapi: '?3\.1\.0'?\ncomponents:\n/);
+        expect(schema).to.contain("\npaths:\n");
+    });
+
+    it('rejects an unknown format', () => {
+        expect(() => generate({ format: "xml" as any })).to.throw("Unknown format 'xml', expected 'json' or 'yaml'");
+    });
+});
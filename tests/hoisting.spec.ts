@@ -0,0 +1,144 @@
+import { expect } from 'chai';
+import { OpenAPIV3 } from 'openapi-types';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api schema hoisting', () => {
+    let schema: OpenAPIV3.Document;
+
+    before(() => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({}),
+                entry: getRootFiles(__dirname, ["test-api/hoisted/*.ts"]),
+                hoistSchemas: true,
+            }
+        });
+
+        schema = JSON.parse(result.openApi.schema || "");
+    });
+
+    it('hoists anonymous bodies and responses using the operation id', () => {
+        expect(schema.paths["/orders"]?.post).to.deep.equal({
+            operationId: "createOrder",
+            requestBody: {
+                content: {
+                    "application/json": {
+                        schema: {
+                            $ref: "#/components/schemas/CreateOrderBody"
+                        }
+                    }
+                },
+                required: true
+            },
+            responses: {
+                201: {
+                    description: "Created order",
+                    content: {
+                        "application/json": {
+                            schema: {
+                                $ref: "#/components/schemas/CreateOrder201Response"
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('reuses structurally identical schemas and names others from the path', () => {
+        expect(schema.paths["/orders/{id}"]?.get?.responses).to.deep.equal({
+            200: {
+                description: "An order or An order with notes",
+                content: {
+                    "application/json": {
+                        schema: {
+                            oneOf: [
+                                { $ref: "#/components/schemas/CreateOrder201Response" },
+                                { $ref: "#/components/schemas/GetOrdersId200Response" }
+                            ]
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('leaves named and primitive schemas in place', () => {
+        expect(schema.paths["/orders"]?.get?.responses).to.deep.equal({
+            200: {
+                description: "Order status",
+                content: {
+                    "application/json": {
+                        schema: {
+                            type: "string"
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('hoists nested anonymous objects and array items', () => {
+        expect(schema.components?.schemas?.GetShipment200Response).to.deep.equal({
+            type: "object",
+            properties: {
+                id: { type: "string" },
+                address: { $ref: "#/components/schemas/GetShipment200ResponseAddress" },
+                lines: { type: "array", items: { $ref: "#/components/schemas/GetShipment200ResponseLinesItem" } }
+            },
+            required: ["address", "id", "lines"]
+        });
+    });
+
+    it('adds hoisted schemas to components', () => {
+        expect(schema.components?.schemas).to.deep.equal({
+            CreateOrderBody: {
+                type: "object",
+                properties: {
+                    note: { type: "string" }
+                }
+            },
+            CreateOrder201Response: {
+                type: "object",
+                properties: {
+                    id: { type: "string" },
+                    total: { type: "number" }
+                },
+                required: ["id", "total"]
+            },
+            GetOrdersId200Response: {
+                type: "object",
+                properties: {
+                    id: { type: "string" },
+                    total: { type: "number" },
+                    notes: { type: "array", items: { type: "string" } }
+                },
+                required: ["id", "notes", "total"]
+            },
+            GetShipment200Response: {
+                type: "object",
+                properties: {
+                    id: { type: "string" },
+                    address: { $ref: "#/components/schemas/GetShipment200ResponseAddress" },
+                    lines: { type: "array", items: { $ref: "#/components/schemas/GetShipment200ResponseLinesItem" } }
+                },
+                required: ["address", "id", "lines"]
+            },
+            GetShipment200ResponseAddress: {
+                type: "object",
+                properties: {
+                    city: { type: "string" }
+                },
+                required: ["city"]
+            },
+            GetShipment200ResponseLinesItem: {
+                type: "object",
+                properties: {
+                    sku: { type: "string" }
+                },
+                required: ["sku"]
+            }
+        });
+    });
+});
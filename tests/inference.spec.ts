@@ -0,0 +1,100 @@
+import { expect, use } from 'chai';
+import deepEqual from 'deep-equal-in-any-order';
+import { OpenAPIV3 } from 'openapi-types';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+use(deepEqual);
+
+describe('open api response inference', () => {
+    let schema: OpenAPIV3.Document;
+
+    before(() => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({}),
+                entry: getRootFiles(__dirname, ["test-api/inferred/*.ts"]),
+                inferResponses: true,
+            }
+        });
+
+        schema = JSON.parse(result.openApi.schema || "");
+    });
+
+    it('infers responses from reply chains', () => {
+        expect(schema.paths["/orders/{id}"]?.get?.responses).to.deep.equal({
+            200: {
+                description: "OK",
+                content: {
+                    "application/json": {
+                        schema: {
+                            $ref: "#/components/schemas/Order"
+                        }
+                    }
+                }
+            },
+            404: {
+                description: "Not Found"
+            },
+            500: {
+                description: "Internal Server Error",
+                content: {
+                    "application/json": {
+                        schema: {
+                            type: "string"
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('infers responses from context assignments', () => {
+        expect(schema.paths["/orders"]?.post?.responses).to.deep.equal({
+            201: {
+                description: "Created",
+                content: {
+                    "application/json": {
+                        schema: {
+                            $ref: "#/components/schemas/Order"
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    it('infers responses from switch cases', () => {
+        expect(schema.paths["/orders/{id}"]?.delete?.responses).to.deep.equal({
+            400: {
+                description: "Bad Request",
+                content: {
+                    "application/json": {
+                        schema: {
+                            type: "string"
+                        }
+                    }
+                }
+            },
+            204: {
+                description: "No Content"
+            }
+        });
+    });
+
+    it('ignores calls and assignments on other receivers', () => {
+        expect(schema.paths["/orders/{id}"]?.put?.responses).to.deep.equal({
+            202: {
+                description: "Accepted"
+            }
+        });
+    });
+
+    it('ignores assignments to the request when a reply is used', () => {
+        expect(schema.paths["/orders/{id}"]?.patch?.responses).to.deep.equal({
+            204: {
+                description: "No Content"
+            }
+        });
+    });
+});
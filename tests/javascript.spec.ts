@@ -0,0 +1,58 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api javascript sources', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/javascript/customers.js"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+
+    it('reads parameters and return types from JSDoc', () => {
+        const operation = schema.paths["/customers/{id}"].get;
+
+        expect(operation.parameters).to.deep.equal([
+            {
+                name: "id",
+                in: "path",
+                schema: {
+                    type: "string"
+                },
+                required: true
+            },
+            {
+                name: "limit",
+                in: "query",
+                schema: {
+                    type: "number"
+                },
+                required: false
+            }
+        ]);
+        expect(operation.responses[200].content["application/json"].schema).to.deep.equal({
+            $ref: "#/components/schemas/Customer"
+        });
+    });
+
+    it('reads request bodies from JSDoc on bare arrow parameters', () => {
+        const operation = schema.paths["/customers/{id}"].patch;
+
+        expect(operation.requestBody.content["application/json"].schema).to.deep.equal({
+            $ref: "#/components/schemas/CustomerPatch"
+        });
+        expect(schema.components.schemas.CustomerPatch.properties).to.deep.equal({
+            name: { type: "string" }
+        });
+    });
+
+    it('reads typedefs and enums', () => {
+        expect(result.diagnostics).to.be.empty;
+        expect(Object.keys(schema.components.schemas.Customer.properties)).to.have.members(["id", "name", "tier"]);
+        expect(schema.components.schemas.CustomerTier).to.deep.equal({
+            anyOf: [{ enum: ["standard", "gold"] }]
+        });
+    });
+});
@@ -0,0 +1,108 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('json schema generator', () => {
+    const entry = getRootFiles(__dirname, ["test-api/contracts/*.ts"]);
+
+    it('writes a self-contained document for each marked type', () => {
+        const result = generateSchemas({
+            jsonSchema: {
+                entry,
+                id: "https://example.com/schemas/",
+            }
+        });
+
+        const schema = JSON.parse(result.jsonSchema?.schemas?.OrderPlaced || "");
+
+        expect(schema.$schema).to.equal("https://json-schema.org/draft/2020-12/schema");
+        expect(schema.$id).to.equal("https://example.com/schemas/OrderPlaced.json");
+        expect(schema.$defs).to.have.keys(["Address", "Customer"]);
+        expect(schema.$defs.Customer.properties.address).to.deep.equal({ $ref: "#/$defs/Address" });
+        expect(JSON.stringify(schema)).not.to.contain("#/components/schemas");
+    });
+
+    it('exports named types and refers to recursive types from the root', () => {
+        const result = generateSchemas({
+            jsonSchema: {
+                entry,
+                types: ["Category"],
+            }
+        });
+
+        expect(result.jsonSchema?.schemas).to.have.keys(["OrderPlaced", "Category"]);
+
+        const schema = JSON.parse(result.jsonSchema?.schemas?.Category || "");
+
+        expect(schema.$id).to.equal("Category.json");
+        expect(schema.properties.children).to.deep.equal({ type: "array", items: { $ref: "#" } });
+        expect(schema).not.to.have.property("$defs");
+    });
+
+    it('only exports named types declared in the entry file', () => {
+        const result = generateSchemas({
+            jsonSchema: {
+                entry: getRootFiles(__dirname, ["test-api/contracts/changes.ts"]),
+                types: ["Change", "Address"],
+            }
+        });
+
+        expect(result.jsonSchema?.schemas).to.have.keys(["Change"]);
+    });
+
+    it('replaces discriminators with plain oneOf', () => {
+        const result = generateSchemas({
+            jsonSchema: {
+                entry: getRootFiles(__dirname, ["test-api/contracts/changes.ts"]),
+                types: ["Change"],
+            }
+        });
+
+        const schema = JSON.parse(result.jsonSchema?.schemas?.Change || "");
+
+        expect(schema).not.to.have.property("discriminator");
+        expect(schema.oneOf).to.deep.equal([
+            { $ref: "#/$defs/AddressChanged" },
+            { $ref: "#/$defs/NameChanged" }
+        ]);
+        expect(schema.$defs).to.have.keys(["Address", "AddressChanged", "NameChanged"]);
+    });
+
+    it('bundles every type under $defs', () => {
+        const result = generateSchemas({
+            jsonSchema: {
+                entry,
+                types: ["Category"],
+                bundle: true,
+            }
+        });
+
+        const schema = JSON.parse(result.jsonSchema?.schema || "");
+
+        expect(schema.$id).to.equal("schemas.json");
+        expect(schema.$defs).to.have.keys(["Address", "Category", "Customer", "OrderPlaced"]);
+        expect(schema.$defs.Category.properties.children.items).to.deep.equal({ $ref: "#/$defs/Category" });
+    });
+
+    it('reports unresolved references', () => {
+        const generate = (unresolvedReferences?: "empty" | "error") => generateSchemas({
+            jsonSchema: {
+                entry: getRootFiles(__dirname, ["test-api/unresolved/contracts/*.ts"]),
+                types: ["FileUploaded"],
+                unresolvedReferences,
+            }
+        });
+
+        const result = generate();
+        const schema = JSON.parse(result.jsonSchema?.schemas?.FileUploaded || "");
+
+        expect(schema.$defs.Blob).to.deep.equal({});
+        expect(result.diagnostics).to.have.lengthOf(1);
+        expect(result.diagnostics[0]).to.deep.include({
+            severity: "warning",
+            message: "Could not resolve the type 'Blob'",
+            line: 3,
+        });
+        expect(() => generate("error")).to.throw(/^Could not resolve the type 'Blob' referenced at .*uploaded\.ts:3:\d+$/);
+    });
+});
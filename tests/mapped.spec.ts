@@ -0,0 +1,76 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api mapped and conditional types', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/mapped/customers.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+    const { Settings } = schema.components.schemas;
+
+    it('evaluates mapped types over keyof', () => {
+        expect(schema.components.schemas.CustomerUpdate).to.deep.equal({
+            type: "object",
+            properties: {
+                id: { type: "string" },
+                name: { type: "string" },
+                email: { type: "string" },
+            }
+        });
+        expect(Settings.properties.update).to.deep.equal(schema.components.schemas.CustomerUpdate);
+    });
+
+    it('applies readonly and optional modifiers', () => {
+        expect(schema.components.schemas.Flags).to.deep.equal({
+            type: "object",
+            properties: {
+                beta: { type: "boolean", readOnly: true },
+                darkMode: { type: "boolean", readOnly: true },
+            },
+            required: ["beta", "darkMode"]
+        });
+    });
+
+    it('filters unions with Exclude, Extract and NonNullable', () => {
+        expect(schema.components.schemas.OpenStatus).to.deep.equal({
+            type: "string",
+            enum: ["active", "suspended"]
+        });
+        expect(schema.components.schemas.ClosedStatus).to.deep.equal({
+            type: "string",
+            enum: ["closed"]
+        });
+        expect(schema.components.schemas.Email).to.deep.equal({ type: "string" });
+    });
+
+    it('expands keyof into the union members it filters', () => {
+        expect(schema.components.schemas.CustomerField).to.deep.equal({
+            type: "string",
+            enum: ["name", "email"]
+        });
+        expect(Settings.properties.editable).to.deep.equal({
+            type: "object",
+            properties: {
+                name: { type: "string" },
+                email: { type: "string" },
+            }
+        });
+    });
+
+    it('distributes conditional types over unions', () => {
+        expect(schema.components.schemas.Kinds).to.deep.equal({
+            type: "string",
+            enum: ["text", "other"]
+        });
+    });
+
+    it('warns about mapped types it cannot evaluate', () => {
+        const diagnostic = result.diagnostics.find(diagnostic => diagnostic.message.includes("mapped type"));
+        expect(diagnostic?.severity).to.equal("warning");
+        expect(Settings.properties.prefixed).to.deep.equal({});
+    });
+});
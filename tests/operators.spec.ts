@@ -0,0 +1,86 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api type operators', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/operators/accounts.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+    const { Account } = schema.components.schemas;
+
+    it('derives enums from as const arrays and objects', () => {
+        expect(schema.components.schemas.AccountStatus).to.deep.equal({
+            type: "string",
+            enum: ["active", "suspended", "closed"]
+        });
+        expect(schema.components.schemas.RegionName).to.deep.equal({
+            type: "string",
+            enum: ["Europe", "United States"]
+        });
+        expect(schema.components.schemas.ErrorCode).to.deep.equal({
+            type: "number",
+            enum: [400, 404]
+        });
+    });
+
+    it('widens the elements of arrays without as const', () => {
+        expect(schema.components.schemas.Currency).to.deep.equal({ type: "string" });
+    });
+
+    it('derives string enums from keyof', () => {
+        expect(schema.components.schemas.Plan).to.deep.equal({
+            type: "string",
+            enum: ["free", "team"]
+        });
+        expect(schema.components.schemas.Region).to.deep.equal({
+            type: "string",
+            enum: ["eu", "us"]
+        });
+    });
+
+    it('evaluates typeof against variable declarations', () => {
+        expect(Account.properties.settings.type).to.equal("object");
+        expect(Account.properties.settings.properties).to.deep.equal({
+            theme: { type: "string" },
+            pageSize: { type: "number" }
+        });
+    });
+
+    it('evaluates indexed access against properties and array elements', () => {
+        expect(schema.components.schemas.AccountAddress).to.deep.equal({
+            $ref: "#/components/schemas/Address"
+        });
+        expect(Account.properties.primaryTag).to.deep.equal({ type: "string" });
+    });
+});
+
+describe('open api type operators that refer back to themselves', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/operators/cycles.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+
+    it('falls back to empty schemas', () => {
+        expect(schema.components.schemas.Element).to.deep.equal({});
+        expect(schema.components.schemas.Left.properties.x).to.deep.equal({});
+        expect(schema.components.schemas.Cycles.properties.config.properties.a).to.deep.equal({});
+        expect(schema.components.schemas.Cycles.properties.recursed).to.deep.equal({});
+    });
+
+    it('reports where the cycle was found', () => {
+        expect(result.diagnostics.map(diagnostic => diagnostic.line)).to.have.members([4, 6, 9, 11, 13]);
+        for (const diagnostic of result.diagnostics) {
+            expect(diagnostic).to.deep.include({
+                severity: "warning",
+                message: "Could not evaluate a type that refers back to itself",
+            });
+        }
+    });
+});
@@ -0,0 +1,39 @@
+import { expect } from 'chai';
+import { OpenAPIV3 } from 'openapi-types';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api output ordering', () => {
+    const generate = () => generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/expressions/*.ts"]),
+        }
+    }).openApi.schema || "";
+
+    it('produces identical output across runs', () => {
+        expect(generate()).to.equal(generate());
+    });
+
+    it('sorts component schemas by name', () => {
+        const schema: OpenAPIV3.Document = JSON.parse(generate());
+        const names = Object.keys(schema.components?.schemas || {});
+
+        expect(names).to.deep.equal([...names].sort());
+    });
+
+    it('keeps properties in declaration order', () => {
+        const schema: OpenAPIV3.Document = JSON.parse(generate());
+        const customer = schema.components?.schemas?.Customer as OpenAPIV3.SchemaObject;
+
+        expect(Object.keys(customer.properties || {})).to.deep.equal(["name", "email"]);
+    });
+
+    it('sorts required properties by name', () => {
+        const schema: OpenAPIV3.Document = JSON.parse(generate());
+        const response = schema.paths["/orders/{id}/summary"]?.get?.responses[200] as OpenAPIV3.ResponseObject;
+        const summary = response.content?.["application/json"].schema as OpenAPIV3.SchemaObject;
+
+        expect(summary.required).to.deep.equal(["customer", "name", "ok", "order", "tags", "total"]);
+    });
+});
@@ -27,7 +27,6 @@ describe('open api generator', () => {
     it('generates schemas', () => {
         expect(schema.components?.schemas).to.deep.equalInAnyOrder({
             Animal: {
-                $id: "#/components/schemas/Animal",
                 properties: {
                     shots: {
                         items: {
@@ -42,7 +41,6 @@ describe('open api generator', () => {
                 type: "object"
             },
             Account: {
-                $id: "#/components/schemas/Account",
                 properties: {
                     number: {
                         type: "string"
@@ -51,7 +49,6 @@ describe('open api generator', () => {
                 type: "object"
             },
             AdminUser: {
-                $id: "#/components/schemas/AdminUser",
                 properties: {
                     name: {
                         type: "string"
@@ -66,7 +63,6 @@ describe('open api generator', () => {
                 type: "object"
             },
             Registration: {
-                $id: "#/components/schemas/Registration",
                 properties: {
                     date: {
                         type: "string"
@@ -75,7 +71,6 @@ describe('open api generator', () => {
                 type: "object"
             },
             CreateUserRequest: {
-                $id: "#/components/schemas/CreateUserRequest",
                 properties: {
                     name: {
                         type: "string"
@@ -84,7 +79,6 @@ describe('open api generator', () => {
                 type: "object"
             },
             AnimalKind: {
-                $id: "#/components/schemas/AnimalKind",
                 enum: [
                     "dog",
                     "cat",
@@ -93,7 +87,6 @@ describe('open api generator', () => {
                 type: "string"
             },
             User: {
-                $id: "#/components/schemas/User",
                 properties: {
                     name: {
                         type: "string"
@@ -102,7 +95,6 @@ describe('open api generator', () => {
                 type: "object"
             },
             AnimalUpdate: {
-                $id: "#/components/schemas/AnimalUpdate",
                 allOf: [
                     {
                         $ref: "#/components/schemas/Registered"
@@ -121,7 +113,6 @@ describe('open api generator', () => {
                 ]
             },
             Registered: {
-                $id: "#/components/schemas/Registered",
                 properties: {
                     serialNumber: {
                         type: "string"
@@ -133,11 +124,9 @@ describe('open api generator', () => {
                 type: "object"
             },
             UserPatch: {
-                $id: "#/components/schemas/UserPatch",
                 type: "object"
             },
             status: {
-                $id: "#/components/schemas/status",
                 properties: {
                     status: {
                         type: "string"
@@ -146,11 +135,9 @@ describe('open api generator', () => {
                 type: "object"
             },
             AdjacentLicense: {
-                $id: "#/components/schemas/AdjacentLicense",
                 $ref: "#/components/schemas/AnimalLicense"
             },
             AnimalLicense: {
-                $id: "#/components/schemas/AnimalLicense",
                 properties: {
                     exp: {
                         type: "string"
@@ -168,7 +155,6 @@ describe('open api generator', () => {
                 type: "object"
             },
             AnimalMood: {
-                $id: "#/components/schemas/AnimalMood",
                 anyOf: [
                     {
                         properties: {
@@ -278,12 +264,8 @@ describe('open api generator', () => {
                         {
                             name: "id",
                             in: "path",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        type: "string"
-                                    }
-                                }
+                            schema: {
+                                type: "string"
                             },
                             required: true
                         }
@@ -311,12 +293,8 @@ describe('open api generator', () => {
                         {
                             name: "kind",
                             in: "query",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        $ref: "#/components/schemas/AnimalKind"
-                                    }
-                                }
+                            schema: {
+                                $ref: "#/components/schemas/AnimalKind"
                             },
                             required: true
                         }
@@ -347,12 +325,8 @@ describe('open api generator', () => {
                         {
                             name: "id",
                             in: "path",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        type: "string"
-                                    }
-                                }
+                            schema: {
+                                type: "string"
                             },
                             required: true
                         }
@@ -375,12 +349,8 @@ describe('open api generator', () => {
                         {
                             name: "id",
                             in: "path",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        type: "string"
-                                    }
-                                }
+                            schema: {
+                                type: "string"
                             },
                             required: true
                         }
@@ -408,25 +378,17 @@ describe('open api generator', () => {
                         {
                             name: "id",
                             in: "path",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        type: "string"
-                                    }
-                                }
+                            schema: {
+                                type: "string"
                             },
                             required: true
                         },
                         {
                             name: "date",
                             in: "query",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        format: "date",
-                                        type: "string"
-                                    }
-                                }
+                            schema: {
+                                format: "date",
+                                type: "string"
                             },
                             required: false
                         }
@@ -445,18 +407,124 @@ describe('open api generator', () => {
                     }
                 }
             },
-            "/animals/{id}": {
+            "/user/{id}/profile": {
                 get: {
                     parameters: [
                         {
                             name: "id",
                             in: "path",
+                            schema: {
+                                type: "string"
+                            },
+                            required: true
+                        }
+                    ],
+                    responses: {
+                        200: {
+                            description: "OK",
                             content: {
                                 "application/json": {
                                     schema: {
-                                        type: "number"
+                                        $ref: "#/components/schemas/User"
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    tags: [
+                        "Users"
+                    ]
+                }
+            },
+            "/animals/search": {
+                get: {
+                    parameters: [
+                        {
+                            name: "kind",
+                            in: "query",
+                            description: "The kind of animal to search for",
+                            schema: {
+                                $ref: "#/components/schemas/AnimalKind"
+                            },
+                            required: true
+                        },
+                        {
+                            name: "name",
+                            in: "query",
+                            schema: {
+                                type: "string"
+                            },
+                            required: false
+                        },
+                        {
+                            name: "sort",
+                            in: "query",
+                            schema: {
+                                anyOf: [
+                                    {
+                                        enum: [
+                                            "asc",
+                                            "desc"
+                                        ]
+                                    }
+                                ]
+                            },
+                            required: false
+                        }
+                    ],
+                    responses: {
+                        200: {
+                            description: "Search animals",
+                            content: {
+                                "application/json": {
+                                    schema: {
+                                        items: {
+                                            type: "string"
+                                        },
+                                        type: "array"
+                                    }
+                                },
+                                "text/csv": {
+                                    schema: {
+                                        items: {
+                                            type: "string"
+                                        },
+                                        type: "array"
                                     }
                                 }
+                            }
+                        },
+                        400: {
+                            description: "Missing kind or Invalid sort",
+                            content: {
+                                "application/json": {
+                                    schema: {
+                                        oneOf: [
+                                            {
+                                                type: "string"
+                                            },
+                                            {
+                                                type: "number"
+                                            }
+                                        ]
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    tags: [
+                        "Animals"
+                    ]
+                }
+            },
+            "/animals/{id}": {
+                get: {
+                    parameters: [
+                        {
+                            name: "id",
+                            in: "path",
+                            schema: {
+                                type: "number"
                             },
                             required: true
                         }
@@ -540,36 +608,37 @@ describe('open api generator', () => {
                         {
                             name: "lat",
                             in: "query",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        type: "number"
-                                    }
-                                }
+                            schema: {
+                                type: "number"
                             },
                             required: false
                         },
                         {
                             name: "long",
                             in: "query",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        type: "number"
-                                    }
-                                }
+                            schema: {
+                                type: "number"
+                            },
+                            required: false
+                        },
+                        {
+                            name: "tags",
+                            in: "query",
+                            style: "pipeDelimited",
+                            explode: false,
+                            schema: {
+                                items: {
+                                    type: "string"
+                                },
+                                type: "array"
                             },
                             required: false
                         },
                         {
                             name: "user",
                             in: "header",
-                            content: {
-                                "application/json": {
-                                    schema: {
-                                        $ref: "#/components/schemas/User"
-                                    }
-                                }
+                            schema: {
+                                $ref: "#/components/schemas/User"
                             },
                             required: true
                         }
@@ -0,0 +1,38 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api parse recovery', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/broken/*.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+
+    it('reports syntax errors with their location', () => {
+        const errors = result.diagnostics.filter(diagnostic => diagnostic.severity === "error");
+
+        expect(errors).to.have.lengthOf(1);
+        expect(errors[0].file).to.match(/broken[\\/]dtos[\\/]shipments\.ts$/);
+        // the error is reported inside the exported declaration, not at its keyword
+        expect(errors[0].line).to.equal(8);
+    });
+
+    it('keeps the declarations before the syntax error', () => {
+        expect(schema.components.schemas.Shipment.properties).to.deep.equal({
+            id: { type: "string" },
+            carrier: { type: "string" },
+        });
+    });
+
+    it('marks the declarations after the syntax error as unresolved', () => {
+        expect(schema.paths["/carriers"]).to.exist;
+        expect(schema.components.schemas.Carrier).to.deep.equal({});
+        expect(result.diagnostics.find(diagnostic => diagnostic.severity === "warning")).to.deep.include({
+            message: "Could not resolve the type 'Carrier'",
+            line: 16,
+        });
+    });
+});
@@ -0,0 +1,19 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api syntax', () => {
+    it('parses decorated classes, tsx and mts files', () => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({}),
+                entry: getRootFiles(__dirname, ["test-api/syntax/*.{ts,tsx,mts}"]),
+            }
+        });
+        const schema = JSON.parse(result.openApi.schema || "");
+
+        expect(result.diagnostics).to.be.empty;
+        expect(Object.keys(schema.paths)).to.have.members(["/reports", "/pages", "/settings"]);
+        expect(schema.components.schemas.Report).to.exist;
+    });
+});
@@ -0,0 +1,35 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api template literal types', () => {
+    const result = generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/templates/widgets.ts"]),
+        }
+    });
+    const schema = JSON.parse(result.openApi.schema || "");
+
+    it('converts placeholders into a pattern', () => {
+        expect(schema.components.schemas.WidgetId).to.deep.equal({
+            type: "string",
+            pattern: "^widget_.*$"
+        });
+        expect(schema.components.schemas.Width).to.deep.equal({
+            type: "string",
+            pattern: "^-?\\d+(?:\\.\\d+)?px$"
+        });
+        expect(schema.components.schemas.Widget.properties.version).to.deep.equal({
+            type: "string",
+            pattern: "^v(?:1|2)\\.-?\\d+$"
+        });
+    });
+
+    it('expands finite unions into an enum', () => {
+        expect(schema.components.schemas.Margin).to.deep.equal({
+            type: "string",
+            enum: ["margin-top-sm", "margin-top-lg", "margin-bottom-sm", "margin-bottom-lg"]
+        });
+    });
+});
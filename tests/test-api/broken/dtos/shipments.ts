@@ -0,0 +1,13 @@
+export interface Shipment {
+    id: string;
+    carrier: string;
+}
+
+export interface ShipmentEvent {
+    shipmentId: string;
+    status: 'shipped' | 'delivered' |;
+}
+
+export interface Carrier {
+    name: string;
+}
@@ -0,0 +1,23 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+import { Carrier, Shipment } from './dtos/shipments';
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Shipment, {
+        statusCode: 200,
+        description: "A shipment",
+    }));
+}, {
+    method: 'GET',
+    path: '/shipments',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Carrier, {
+        statusCode: 200,
+        description: "A carrier",
+    }));
+}, {
+    method: 'GET',
+    path: '/carriers',
+}));
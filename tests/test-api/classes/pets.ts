@@ -0,0 +1,76 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+class Entity {
+    id: string;
+    private secret: string;
+}
+
+class Pet extends Entity {
+    static registry: string[] = [];
+    #token: string;
+    protected owner: string;
+    readonly species: string;
+    nickname?: string;
+
+    constructor(public name: string, public readonly born: string, private vet: string, protected clinic: string) {
+        super();
+    }
+
+    get age(): number {
+        return 0;
+    }
+
+    get label(): string {
+        return this.nickname || this.name;
+    }
+
+    set label(value: string) {
+        this.nickname = value;
+    }
+
+    speak(): string {
+        return "";
+    }
+}
+
+class Box<T> {
+    value: T;
+}
+
+class NamedBox extends Box<string> {
+    name: string;
+}
+
+const mix = <T>(base: T): T => base;
+
+class MixedPet extends mix(Entity) {
+    name: string;
+}
+
+interface PetReference {
+    id: Pet["id"];
+    field: keyof Pet;
+    box: NamedBox;
+    mixed: MixedPet;
+}
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Pet, {
+        statusCode: 200,
+        description: "A pet",
+    }));
+}, {
+    method: 'GET',
+    path: '/pets/{id}',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as PetReference, {
+        statusCode: 200,
+        description: "A reference to a pet",
+    }));
+}, {
+    method: 'GET',
+    path: '/pets/{id}/reference',
+}));
@@ -0,0 +1,4 @@
+export interface User {
+    email: string;
+    roles: string[];
+}
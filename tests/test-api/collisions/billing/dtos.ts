@@ -0,0 +1,4 @@
+export interface User {
+    accountId: string;
+    balance: number;
+}
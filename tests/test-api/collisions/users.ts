@@ -0,0 +1,18 @@
+import { LilBodyParam, LilPath } from '../../../src';
+import { Router } from '../routes/router';
+import { User as AuthUser } from './auth/dtos';
+import { User } from './billing/dtos';
+
+Router.post("", {}, LilPath(async (request: { body: LilBodyParam<User> }, reply: any): Promise<void> => {
+    reply.send();
+}, {
+    method: 'POST',
+    path: '/billing/users',
+}));
+
+Router.post("", {}, LilPath(async (request: { body: LilBodyParam<AuthUser> }, reply: any): Promise<void> => {
+    reply.send();
+}, {
+    method: 'POST',
+    path: '/auth/users',
+}));
@@ -0,0 +1,5 @@
+class Customer {
+    name: string = "";
+}
+
+export = Customer;
@@ -0,0 +1,12 @@
+class InvoiceRecord {
+    number: string = "";
+    total: number = 0;
+}
+
+class LineItem {
+    description: string = "";
+    quantity: number = 0;
+}
+
+module.exports = { Invoice: InvoiceRecord };
+exports.InvoiceLine = LineItem;
@@ -0,0 +1,46 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+import Customer = require('./customer');
+
+const { Invoice, InvoiceLine: Line } = require('./dtos');
+const { User } = require('./users');
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Invoice, {
+        statusCode: 200,
+        description: "An invoice",
+    }));
+}, {
+    method: 'GET',
+    path: '/invoices',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Line, {
+        statusCode: 200,
+        description: "An invoice line",
+    }));
+}, {
+    method: 'GET',
+    path: '/invoice-lines',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Customer, {
+        statusCode: 200,
+        description: "A customer",
+    }));
+}, {
+    method: 'GET',
+    path: '/customers',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as User, {
+        statusCode: 200,
+        description: "A user",
+    }));
+}, {
+    method: 'GET',
+    path: '/users',
+}));
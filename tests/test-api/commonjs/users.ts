@@ -0,0 +1,9 @@
+class User {
+    email: string = "";
+}
+
+function createUser(email: string): User {
+    return { email };
+}
+
+module.exports = { User, createUser };
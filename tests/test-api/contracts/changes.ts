@@ -0,0 +1,13 @@
+import { Address } from './events';
+
+interface AddressChanged {
+    kind: "address";
+    address: Address;
+}
+
+interface NameChanged {
+    kind: "name";
+    name: string;
+}
+
+export type Change = AddressChanged | NameChanged;
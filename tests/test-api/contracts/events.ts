@@ -0,0 +1,21 @@
+import { LilSchema } from '../../../src';
+
+export interface Address {
+    street: string;
+    city: string;
+}
+
+export interface Customer {
+    name: string;
+    address: Address;
+}
+
+export type OrderPlaced = LilSchema<{
+    orderId: string;
+    customer: Customer;
+}>;
+
+export interface Category {
+    name: string;
+    children: Category[];
+}
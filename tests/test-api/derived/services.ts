@@ -0,0 +1,31 @@
+export interface User {
+    id: string;
+    name: string;
+}
+
+export class UserModel {
+    id: string;
+    email: string;
+}
+
+class UserService {
+    async get(id: string): Promise<User> {
+        return { id, name: "" };
+    }
+
+    list(page: number, size: number): User[] {
+        return [];
+    }
+}
+
+export const userService = new UserService();
+
+export function findUser(id: string, includeDeleted?: boolean): User | undefined {
+    return undefined;
+}
+
+export function searchUsers(query: string, ...tags: string[]): User[] {
+    return [];
+}
+
+export const countUsers = async (): Promise<number> => 0;
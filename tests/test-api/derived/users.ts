@@ -0,0 +1,27 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+import { countUsers, findUser, searchUsers, userService, UserModel } from './services';
+
+const defaultLimit = 10;
+
+interface UserSummary {
+    user: Awaited<ReturnType<typeof userService.get>>;
+    users: ReturnType<typeof userService.list>;
+    count: Awaited<ReturnType<typeof countUsers>>;
+    lookup: Parameters<typeof findUser>;
+    search: Parameters<typeof searchUsers>;
+    model: InstanceType<typeof UserModel>;
+    limit: ReturnType<typeof defaultLimit>;
+    range: [number, number];
+    labels: [string, number?, ...boolean[]];
+}
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as UserSummary, {
+        statusCode: 200,
+        description: "A summary of users",
+    }));
+}, {
+    method: 'GET',
+    path: '/users/summary',
+}));
@@ -0,0 +1,14 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+const loop = () => loop();
+
+Router.get("", {}, LilPath(async (request: {}, reply: any): Promise<void> => {
+    reply.send(LilResponse(loop(), {
+        statusCode: 200,
+        description: "Loop",
+    }));
+}, {
+    method: 'GET',
+    path: '/loop',
+}));
@@ -0,0 +1,17 @@
+import { LilPath } from '../../../src';
+import { Router } from '../routes/router';
+
+Router.get("", {}, LilPath(async (request: {}, reply: any): Promise<void> => {
+    reply.send();
+}, {
+    // @ts-expect-error
+    method: 'CONNECT',
+    path: '/tunnels',
+}));
+
+Router.get("", {}, LilPath(async (request: {}, reply: any): Promise<void> => {
+    reply.send();
+}, {
+    method: 'GET',
+    path: '/tunnels',
+}));
@@ -51,4 +51,11 @@ export interface AnimalLicense {
     adjacents: Array<AdjacentLicense>;
 }
 
-export type AdjacentLicense = AnimalLicense;
\ No newline at end of file
+export type AdjacentLicense = AnimalLicense;
+
+export interface AnimalSearch {
+    /** The kind of animal to search for */
+    kind: AnimalKind;
+    name?: string;
+    sort?: "asc" | "desc";
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+export interface Customer {
+    name: string;
+    email: string;
+}
+
+export async function findCustomer(): Promise<Customer> {
+    return { name: "", email: "" };
+}
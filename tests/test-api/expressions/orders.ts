@@ -0,0 +1,79 @@
+import { LilPath, LilResponse, LilRouteParam } from '../../../src';
+import { Router } from '../routes/router';
+import { Customer, findCustomer } from './customers';
+
+interface Order {
+    id: string;
+    total: number;
+    customer: Customer;
+}
+
+function getOrder(): Order {
+    return {} as Order;
+}
+
+export const Status = { Open: "open", Closed: "closed" } as const;
+export type Status = "open" | "closed";
+
+interface Shipment {
+    status: Status;
+}
+
+const listOrders = async (): Promise<Order[]> => [];
+
+Router.get("", {}, LilPath(async (request: { id: LilRouteParam<string, true>; }, reply: any): Promise<void> => {
+    const order = getOrder();
+    const customer = await findCustomer();
+
+    reply.send(LilResponse({ ok: true, order, customer, total: order.total, name: order.customer.name, tags: ["new", "paid"] }, {
+        statusCode: 200,
+        description: "Order summary",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders/{id}/summary',
+}));
+
+Router.get("", {}, LilPath(async (request: { id: LilRouteParam<string, true>; }, reply: any): Promise<void> => {
+    const found = request.id.length > 0;
+
+    reply.send(LilResponse(found ? <Order>{} : null, {
+        statusCode: 200,
+        description: "Maybe an order",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders/{id}',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse(await listOrders(), {
+        statusCode: 200,
+        description: "All orders",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    const guest = { name: "", email: "" } satisfies Customer;
+
+    reply.send(LilResponse(guest!, {
+        statusCode: 200,
+        description: "A customer",
+    }));
+}, {
+    method: 'GET',
+    path: '/customers/{id}',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({ shipment: {} as Shipment, statuses: Status }, {
+        statusCode: 200,
+        description: "Shipment statuses",
+    }));
+}, {
+    method: 'GET',
+    path: '/shipments',
+}));
@@ -0,0 +1,49 @@
+import { LilBodyParam, LilPath, LilResponse, LilRouteParam } from '../../../src';
+import { Router } from '../routes/router';
+
+Router.post("", {}, LilPath(async (request: { body: LilBodyParam<{ note: string }, true> }, reply: any): Promise<void> => {
+    reply.send(LilResponse({ id: "", total: 0 }, {
+        statusCode: 201,
+        description: "Created order",
+    }));
+}, {
+    method: 'POST',
+    path: '/orders',
+    operationId: 'createOrder',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse("ok", {
+        statusCode: 200,
+        description: "Order status",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders',
+}));
+
+Router.get("", {}, LilPath(async (request: { id: LilRouteParam<string, true> }, reply: any): Promise<void> => {
+    reply.send(LilResponse({ id: "", total: 0 }, {
+        statusCode: 200,
+        description: "An order",
+    }));
+
+    reply.send(LilResponse({ id: "", total: 0, notes: [""] }, {
+        statusCode: 200,
+        description: "An order with notes",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders/{id}',
+}));
+
+Router.get("", {}, LilPath(async (request: { id: LilRouteParam<string, true> }, reply: any): Promise<void> => {
+    reply.send(LilResponse({ id: "", address: { city: "" }, lines: [{ sku: "" }] }, {
+        statusCode: 200,
+        description: "A shipment",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders/{id}/shipment',
+    operationId: 'getShipment',
+}));
@@ -0,0 +1,67 @@
+import { LilPath, LilRouteParam } from '../../../src';
+import { Router } from '../routes/router';
+
+const NOT_FOUND = 404;
+
+interface Order {
+    id: string;
+    total: number;
+}
+
+Router.get("", {}, LilPath(async (request: { id: LilRouteParam<string, true>; }, reply: any): Promise<void> => {
+    const order = {} as Order;
+
+    if (!order) {
+        return reply.code(NOT_FOUND).send();
+    }
+
+    try {
+        reply.code(200).send(order);
+    } catch (error) {
+        reply.code(500).send("Could not load order");
+    }
+}, {
+    method: 'GET',
+    path: '/orders/{id}',
+}));
+
+Router.post("", {}, LilPath(async (ctx: any): Promise<void> => {
+    ctx.body = {} as Order;
+    ctx.status = 201;
+}, {
+    method: 'POST',
+    path: '/orders',
+}));
+
+Router.delete("", {}, LilPath(async (request: { id: LilRouteParam<string, true>; }, res: any): Promise<void> => {
+    switch (request.id) {
+        case "":
+            res.status(400).json("Missing id");
+            break;
+        default:
+            res.status(204).send();
+    }
+}, {
+    method: 'DELETE',
+    path: '/orders/{id}',
+}));
+
+Router.put("", {}, LilPath(async (ctx: any, reply: any): Promise<void> => {
+    const socket = {} as any;
+    const cache = {} as any;
+
+    socket.send({} as Order);
+    cache.body = "stale";
+    reply.code(202).send();
+}, {
+    method: 'PUT',
+    path: '/orders/{id}',
+}));
+
+Router.patch("", {}, LilPath(async (req: any, res: any): Promise<void> => {
+    req.body = {} as Order;
+    res.status(204).send();
+}, {
+    method: 'PATCH',
+    path: '/orders/{id}',
+}));
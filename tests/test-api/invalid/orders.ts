@@ -0,0 +1,24 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse("ok", {
+        statusCode: 200,
+        description: "Order status",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders',
+    operationId: 'getOrders',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse("ok", {
+        statusCode: 200,
+        description: "Archived order status",
+    }));
+}, {
+    method: 'GET',
+    path: '/archive/orders',
+    operationId: 'getOrders',
+}));
@@ -0,0 +1,31 @@
+import { LilPath } from '../../../src';
+import { Router } from '../routes/router';
+
+// shares its name with the JSDoc type imported below
+const CustomerPatch = Object.freeze(['name']);
+
+Router.get("", {}, LilPath(
+    /**
+     * @param {Object} request
+     * @param {import('../../../src').LilRouteParam<string, true>} request.id
+     * @param {import('../../../src').LilQueryParam<number, false>} request.limit
+     * @returns {Promise<import('./dtos').Customer>}
+     */
+    async (request) => {
+        return {};
+    }, {
+    method: 'GET',
+    path: '/customers/{id}',
+}));
+
+Router.patch("", {}, LilPath(
+    /**
+     * @param {{ body: import('../../../src').LilBodyParam<import('./dtos').CustomerPatch, true> }} request
+     * @returns {Promise<import('./dtos').Customer>}
+     */
+    async request => {
+        return {};
+    }, {
+    method: 'PATCH',
+    path: '/customers/{id}',
+}));
@@ -0,0 +1,17 @@
+/**
+ * @typedef {Object} Customer A paying customer
+ * @property {string} id
+ * @property {string} name - The customer's full name
+ * @property {CustomerTier} [tier]
+ */
+
+/**
+ * @typedef {Object} CustomerPatch
+ * @property {string=} name
+ */
+
+/** @enum {string} */
+export const CustomerTier = {
+    Standard: 'standard',
+    Gold: 'gold',
+};
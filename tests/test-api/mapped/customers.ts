@@ -0,0 +1,52 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+interface Customer {
+    id: string;
+    name: string;
+    email: string;
+}
+
+type Status = "active" | "suspended" | "closed";
+type OpenStatus = Exclude<Status, "closed">;
+type ClosedStatus = Extract<Status, "closed" | "archived">;
+type Email = NonNullable<string | null | undefined>;
+type CustomerField = Exclude<keyof Customer, "id">;
+type Editable<T> = { [K in Exclude<keyof T, "id">]: T[K] };
+type CustomerUpdate = { [K in keyof Customer]?: Customer[K] };
+type Updates<T> = { [K in keyof T]?: T[K] };
+type Flags = { readonly [K in "beta" | "darkMode"]-?: boolean };
+type IsText<T> = T extends string ? "text" : "other";
+type Kinds = IsText<string | number>;
+
+interface Settings {
+    status: OpenStatus;
+    closed: ClosedStatus;
+    email: Email;
+    update: Updates<Customer>;
+    field: CustomerField;
+    editable: Editable<Customer>;
+    flags: Flags;
+    kinds: Kinds;
+    prefixed: { [K in keyof Customer as `x_${K}`]: string };
+}
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Settings, {
+        statusCode: 200,
+        description: "Customer settings",
+    }));
+}, {
+    method: 'GET',
+    path: '/customers/{id}/settings',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as CustomerUpdate, {
+        statusCode: 200,
+        description: "A pending customer update",
+    }));
+}, {
+    method: 'GET',
+    path: '/customers/{id}/update',
+}));
@@ -0,0 +1,60 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+import { ACCOUNT_STATUSES, CURRENCIES, ERROR_CODES, PLANS } from './constants';
+
+const REGIONS = {
+    eu: "Europe",
+    us: "United States",
+} as const;
+
+const defaultSettings = {
+    theme: "dark",
+    pageSize: 25,
+};
+
+interface Address {
+    street: string;
+    city: string;
+}
+
+interface Account {
+    id: string;
+    address: Address;
+    status: AccountStatus;
+    plan: Plan;
+    region: Region;
+    regionName: RegionName;
+    settings: typeof defaultSettings;
+    tags: string[];
+    primaryTag: Account["tags"][number];
+    lastError: ErrorCode;
+    currency: Currency;
+}
+
+type AccountStatus = (typeof ACCOUNT_STATUSES)[number];
+type ErrorCode = (typeof ERROR_CODES)[number];
+type Currency = (typeof CURRENCIES)[number];
+type Plan = keyof typeof PLANS;
+type Region = keyof typeof REGIONS;
+type RegionName = (typeof REGIONS)[keyof typeof REGIONS];
+type AccountAddress = Account["address"];
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Account, {
+        statusCode: 200,
+        description: "An account",
+    }));
+}, {
+    method: 'GET',
+    path: '/accounts/{id}',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as AccountAddress, {
+        statusCode: 200,
+        description: "The account's address",
+    }));
+}, {
+    method: 'GET',
+    path: '/accounts/{id}/address',
+}));
@@ -0,0 +1,10 @@
+export const ACCOUNT_STATUSES = ["active", "suspended", "closed"] as const;
+
+export const ERROR_CODES = [400, 404] as const;
+
+export const CURRENCIES = ["EUR", "USD"];
+
+export const PLANS = {
+    free: { seats: 1 },
+    team: { seats: 10 },
+} as const;
@@ -0,0 +1,33 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+type Element = Element[number];
+
+interface Left { x: Right["y"] }
+interface Right { y: Left["x"] }
+
+type Suffixed = `${Suffixed}x`;
+
+const config = { a: config.a } as const;
+
+function recurse(): ReturnType<typeof recurse> {
+    return recurse();
+}
+
+interface Cycles {
+    element: Element;
+    left: Left;
+    suffixed: Suffixed;
+    config: typeof config;
+    recursed: ReturnType<typeof recurse>;
+}
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Cycles, {
+        statusCode: 200,
+        description: "Types that refer back to themselves",
+    }));
+}, {
+    method: 'GET',
+    path: '/cycles',
+}));
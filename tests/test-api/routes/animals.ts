@@ -1,5 +1,5 @@
-import { LilBodyParam, LilPath, LilRequiredProp, LilResponse, LilRouteParam, LilSub } from '../../../src';
-import { AnimalsRequest, Request, AnimalUpdate, AnimalLicense } from '../dtos/requests';
+import { LilBodyParam, LilPath, LilQueryParams, LilRequiredProp, LilResponse, LilRouteParam, LilSub } from '../../../src';
+import { AnimalsRequest, Request, AnimalUpdate, AnimalLicense, AnimalSearch } from '../dtos/requests';
 import { Router } from './router';
 
 class Animal {
@@ -19,6 +19,34 @@ export default Router.get("", {}, LilPath(async (request: Request<AnimalsRequest
     tags: ['Animals'],
 }));
 
+Router.get("", {}, LilPath(async (request: Request<{ Querystring: LilQueryParams<AnimalSearch> }>, reply: any): Promise<void> => {
+    const search = {} as AnimalSearch;
+
+    if (!search.kind) {
+        return reply.code(400).send(LilResponse("kind is required", {
+            statusCode: 400,
+            description: "Missing kind",
+        }));
+    }
+
+    if (search.sort && search.sort !== "asc" && search.sort !== "desc") {
+        return reply.code(400).send(LilResponse(3, {
+            statusCode: 400,
+            description: "Invalid sort",
+        }));
+    }
+
+    reply.send(LilResponse([] as Array<string>, {
+        statusCode: 200,
+        description: "Search animals",
+        mediaType: ["application/json", "text/csv"],
+    }));
+}, {
+    method: 'GET',
+    path: '/animals/search',
+    tags: ['Animals'],
+}));
+
 Router.get("", {}, LilPath(async (request: Request<{ Querystring: { id: LilRouteParam<number> } }>, reply: any): Promise<void> => {
     await reply.send(LilResponse(new Animal("Billy", ["tetnis", "rabies"]), {
         statusCode: 200,
@@ -1,9 +1,9 @@
-import { LilBodyParam, LilHeader, LilPath, LilQueryParam, LilResponse, LilRouteParam } from '../../../src';
+import { LilBodyParam, LilHeader, LilParamStyle, LilPath, LilQueryParam, LilResponse, LilRouteParam } from '../../../src';
 import AdminUser, { Account, CreateUserRequest, User, UserPatch } from '../dtos';
 import { GetAccountRequest } from '../dtos/requests';
 import { Router } from './router';
 
-export default Router.get("", {}, LilPath(async (request: { lat: LilQueryParam<number, false>, long: LilQueryParam<number, false>; headers: { user: LilHeader<User, true>; }; }, reply: any): Promise<void> => {
+export default Router.get("", {}, LilPath(async (request: { lat: LilQueryParam<number, false>, long: LilQueryParam<number, false>; tags: LilQueryParam<string[], false, undefined, LilParamStyle<"pipeDelimited", false>>; headers: { user: LilHeader<User, true>; }; }, reply: any): Promise<void> => {
     let success = {} as User;
 
     let response = LilResponse(success, {
@@ -19,6 +19,14 @@ export default Router.get("", {}, LilPath(async (request: { lat: LilQueryParam<n
     tags: ['Users'],
 }));
 
+Router.get("", {}, LilPath(async (request: { id: LilRouteParam<string, true>; }): Promise<User> => {
+    return {} as User;
+}, {
+    method: 'GET',
+    path: '/user/{id}/profile',
+    tags: ['Users'],
+}));
+
 Router.patch("", {}, LilPath(async (request: { id: LilRouteParam<string, true>; date: LilQueryParam<string, false, "date">; }, reply: any): Promise<void> => {
     let admin = new AdminUser();
 
@@ -0,0 +1,18 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+declare const renderToString: (element: JSX.Element) => string;
+
+const Page = ({ title }: { title: string }) => <main><h1>{title}</h1></main>;
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(renderToString(<Page title="Home" />));
+    reply.send(LilResponse("", {
+        statusCode: 200,
+        description: "A rendered page",
+        mediaType: "text/html",
+    }));
+}, {
+    method: 'GET',
+    path: '/pages',
+}));
@@ -0,0 +1,21 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+const Entity = (): ClassDecorator => target => target;
+const Column = (): PropertyDecorator => () => { };
+
+@Entity()
+export class Report {
+    @Column()
+    title: string = "";
+}
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Report, {
+        statusCode: 200,
+        description: "A report",
+    }));
+}, {
+    method: 'GET',
+    path: '/reports',
+}));
@@ -0,0 +1,12 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse("ok", {
+        statusCode: 200,
+        description: "Settings",
+    }));
+}, {
+    method: 'GET',
+    path: '/settings',
+}));
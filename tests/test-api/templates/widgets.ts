@@ -0,0 +1,25 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+type WidgetId = `widget_${string}`;
+type Width = `${number}px`;
+type Side = "top" | "bottom";
+type Size = "sm" | "lg";
+type Margin = `margin-${Side}-${Size}`;
+
+interface Widget {
+    id: WidgetId;
+    width: Width;
+    margin: Margin;
+    version: `v${1 | 2}.${bigint}`;
+}
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Widget, {
+        statusCode: 200,
+        description: "A widget",
+    }));
+}, {
+    method: 'GET',
+    path: '/widgets/{id}',
+}));
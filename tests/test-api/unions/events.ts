@@ -0,0 +1,59 @@
+import { LilPath, LilResponse } from '../../../src';
+import { Router } from '../routes/router';
+
+interface OrderCreated {
+    kind: "created";
+    id: string;
+}
+
+interface OrderDeleted {
+    kind: "deleted";
+    id: string;
+    reason: string;
+}
+
+type OrderEvent = OrderCreated | OrderDeleted;
+
+type Shape = { radius: number } | { width: number };
+
+type Priority = 1 | 2 | 3;
+
+type Toggle = true | false;
+
+type Size = "auto" | 0;
+
+interface Preferences {
+    priority: Priority;
+    enabled: Toggle;
+    size: Size;
+}
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as OrderEvent, {
+        statusCode: 200,
+        description: "The latest order event",
+    }));
+}, {
+    method: 'GET',
+    path: '/orders/events/latest',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Shape, {
+        statusCode: 200,
+        description: "A shape",
+    }));
+}, {
+    method: 'GET',
+    path: '/shapes/{id}',
+}));
+
+Router.get("", {}, LilPath(async (request: any, reply: any): Promise<void> => {
+    reply.send(LilResponse({} as Preferences, {
+        statusCode: 200,
+        description: "Display preferences",
+    }));
+}, {
+    method: 'GET',
+    path: '/preferences',
+}));
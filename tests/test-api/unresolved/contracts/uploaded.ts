@@ -0,0 +1,4 @@
+export interface FileUploaded {
+    name: string;
+    data: Blob;
+}
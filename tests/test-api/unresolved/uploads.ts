@@ -0,0 +1,9 @@
+import { LilBodyParam, LilPath } from '../../../src';
+import { Router } from '../routes/router';
+
+Router.post("", {}, LilPath(async (request: { body: LilBodyParam<ArrayBuffer> }, reply: any): Promise<void> => {
+    reply.send();
+}, {
+    method: 'POST',
+    path: '/uploads',
+}));
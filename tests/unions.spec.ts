@@ -0,0 +1,63 @@
+import { expect } from 'chai';
+import { OpenAPIV3 } from 'openapi-types';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api unions', () => {
+    let schema: OpenAPIV3.Document;
+
+    before(() => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({}),
+                entry: getRootFiles(__dirname, ["test-api/unions/*.ts"]),
+            }
+        });
+
+        schema = JSON.parse(result.openApi.schema || "");
+    });
+
+    it('emits discriminated unions as oneOf with a mapping', () => {
+        expect(schema.components?.schemas?.OrderEvent).to.deep.equal({
+            oneOf: [
+                { $ref: "#/components/schemas/OrderCreated" },
+                { $ref: "#/components/schemas/OrderDeleted" }
+            ],
+            discriminator: {
+                propertyName: "kind",
+                mapping: {
+                    created: "#/components/schemas/OrderCreated",
+                    deleted: "#/components/schemas/OrderDeleted"
+                }
+            }
+        });
+    });
+
+    it('defines the members of discriminated unions', () => {
+        expect(Object.keys(schema.components?.schemas || {})).to.include.members(["OrderCreated", "OrderDeleted"]);
+    });
+
+    it('keeps unions without a discriminator as anyOf', () => {
+        expect(schema.components?.schemas?.Shape).to.deep.equal({
+            anyOf: [
+                { type: "object", properties: { radius: { type: "number" } } },
+                { type: "object", properties: { width: { type: "number" } } }
+            ]
+        });
+    });
+
+    it('keeps number and boolean literals typed in enums', () => {
+        expect(schema.components?.schemas?.Priority).to.deep.equal({
+            anyOf: [{ enum: [1, 2, 3] }]
+        });
+        expect(schema.components?.schemas?.Toggle).to.deep.equal({
+            anyOf: [{ enum: [true, false] }]
+        });
+    });
+
+    it('keeps mixed literals in a single enum', () => {
+        expect(schema.components?.schemas?.Size).to.deep.equal({
+            anyOf: [{ enum: ["auto", 0] }]
+        });
+    });
+});
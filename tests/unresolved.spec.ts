@@ -0,0 +1,32 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api unresolved references', () => {
+    const generate = (unresolvedReferences?: "empty" | "error") => generateSchemas({
+        openApi: {
+            base: JSON.stringify({}),
+            entry: getRootFiles(__dirname, ["test-api/unresolved/*.ts"]),
+            unresolvedReferences,
+        }
+    });
+
+    it('falls back to an empty schema and reports where the type was referenced', () => {
+        const result = generate();
+        const schema = JSON.parse(result.openApi.schema || "");
+
+        expect(schema.components.schemas.ArrayBuffer).to.deep.equal({});
+        expect(result.diagnostics).to.have.lengthOf(1);
+        expect(result.diagnostics[0]).to.deep.include({
+            severity: "warning",
+            message: "Could not resolve the type 'ArrayBuffer'",
+            line: 4,
+        });
+        expect(result.diagnostics[0].file).to.match(/unresolved[\\/]uploads\.ts$/);
+        expect(result.diagnostics[0].column).to.be.a("number");
+    });
+
+    it('fails when unresolved references are errors', () => {
+        expect(() => generate("error")).to.throw(/^Could not resolve the type 'ArrayBuffer' referenced at .*unresolved[\\/]uploads\.ts:4:\d+$/);
+    });
+});
@@ -0,0 +1,49 @@
+import { expect } from 'chai';
+import { generateSchemas } from '../src/generator';
+import { getRootFiles } from '../src/utils';
+
+describe('open api validation', () => {
+    const info = { title: "Orders", version: "1.0.0" };
+
+    it('returns no errors for a valid schema', () => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({ info }),
+                entry: getRootFiles(__dirname, ["test-api/expressions/*.ts"]),
+            }
+        });
+
+        expect(result.openApi.errors).to.deep.equal([]);
+    });
+
+    it('reports meta-schema violations, unresolved references and duplicate operation ids', () => {
+        const result = generateSchemas({
+            openApi: {
+                base: JSON.stringify({
+                    components: {
+                        responses: {
+                            NotFound: { $ref: "#/components/schemas/Missing" }
+                        }
+                    }
+                }),
+                entry: getRootFiles(__dirname, ["test-api/invalid/*.ts"]),
+            }
+        });
+
+        const errors = result.openApi.errors || [];
+
+        expect(errors.some(error => error.startsWith("#: ") && error.includes("info"))).to.be.true;
+        expect(errors).to.include("#/components/responses/NotFound: Unresolved reference '#/components/schemas/Missing'");
+        expect(errors).to.include("#/paths/~1archive~1orders/get: Duplicate operationId 'getOrders' is also used at #/paths/~1orders/get");
+    });
+
+    it('fails generation when asked to', () => {
+        expect(() => generateSchemas({
+            openApi: {
+                base: JSON.stringify({ info }),
+                entry: getRootFiles(__dirname, ["test-api/invalid/*.ts"]),
+                failOnInvalid: true,
+            }
+        })).to.throw("Duplicate operationId 'getOrders'");
+    });
+});